@@ -6,6 +6,10 @@ use std::path::PathBuf;
 
 use structopt::StructOpt;
 
+use compiler_tester::Corpus;
+use compiler_tester::ListingFormat;
+use compiler_tester::ModeSpecification;
+use compiler_tester::ReportFormat;
 use compiler_tester::Workflow;
 
 ///
@@ -34,9 +38,10 @@ pub struct Arguments {
     #[structopt(short = "T", long = "trace", parse(from_occurrences))]
     pub trace: usize,
 
-    /// Runs tests only in modes that contain any string from the specified ones.
+    /// Runs tests only in modes matching any of the specified mode specifications, e.g. `Y+M^B3 >=0.8.20`.
+    /// The specifications prefixed with `!` exclude the matching modes, e.g. `!M0`.
     #[structopt(short = "m", long = "mode")]
-    pub modes: Vec<String>,
+    pub modes: Vec<ModeSpecification>,
 
     /// Runs only tests whose name contains any string from the specified ones.
     #[structopt(short = "p", long = "path")]
@@ -46,14 +51,86 @@ pub struct Arguments {
     #[structopt(short = "g", long = "group")]
     pub groups: Vec<String>,
 
+    /// Adds a test corpus, e.g. `path=../tests/solidity,collection=MatterLabsDirectory,language=Solidity`.
+    /// The optional `extension` key overrides the language default extension.
+    #[structopt(long = "corpus")]
+    pub corpora: Vec<Corpus>,
+
+    /// Adds the test corpora listed in the specified YAML or JSON file.
+    #[structopt(long = "corpora-config")]
+    pub corpora_config: Option<PathBuf>,
+
+    /// Runs only the (test, case, mode) triples which have not passed in the specified previous report.
+    /// The report must be in the `json` format.
+    #[structopt(long = "rerun-from")]
+    pub rerun_from: Option<PathBuf>,
+
+    /// Runs only the (test, mode) pairs of the specified zero-based shard.
+    /// Must be used together with `--shard-count`.
+    #[structopt(long = "shard-index")]
+    pub shard_index: Option<usize>,
+
+    /// Splits the (test, mode) pairs into the specified number of deterministic shards.
+    /// Must be used together with `--shard-index`.
+    #[structopt(long = "shard-count")]
+    pub shard_count: Option<usize>,
+
     /// The benchmark output path, if requested.
     #[structopt(short = "b", long = "benchmark")]
     pub benchmark: Option<PathBuf>,
 
+    /// The machine-readable report output path, if requested.
+    #[structopt(long = "report")]
+    pub report: Option<PathBuf>,
+
+    /// The machine-readable report format: `junit` or `json`.
+    /// The `json` format emits one record per line.
+    #[structopt(long = "report-format", default_value = "json")]
+    pub report_format: ReportFormat,
+
+    /// The known failures baseline file path, if requested.
+    /// The listed tests are reported as `XFAIL`, or `XPASS` if they pass, and do not fail the run.
+    #[structopt(long = "baseline")]
+    pub baseline: Option<PathBuf>,
+
     /// Sets the number of threads, which execute the tests concurrently.
     #[structopt(short = "t", long = "threads")]
     pub threads: Option<usize>,
 
+    /// Runs the tests in the specified number of isolated worker processes.
+    /// A crash of a worker is reported as the outcome of its current test.
+    #[structopt(long = "workers")]
+    pub workers: Option<usize>,
+
+    /// Runs as a worker process, serving the test requests of the parent process.
+    #[structopt(long = "worker", hidden = true)]
+    pub worker: bool,
+
+    /// Sets the default EraVM cycles limit per transaction, which is 100000000 if unset.
+    /// Exceeding it is reported as a failure. Test cases may override it with `cycles`.
+    #[structopt(long = "cycles-limit")]
+    pub cycles_limit: Option<usize>,
+
+    /// Sets the default EraVM ergs limit per transaction, which is 1000000000 if unset.
+    /// Exceeding it is reported as a failure. Test cases may override it with `ergs`.
+    #[structopt(long = "ergs-limit")]
+    pub ergs_limit: Option<u64>,
+
+    /// Sets the default EVM transaction gas limit.
+    /// Exhausting it is reported as a failure. Test cases may override it with `gas`.
+    #[structopt(long = "gas-limit")]
+    pub gas_limit: Option<u64>,
+
+    /// Sets the wall-clock timeout per test in seconds. Requires `--workers`.
+    /// A worker exceeding it is killed and replaced.
+    #[structopt(long = "timeout")]
+    pub timeout: Option<u64>,
+
+    /// Runs each EraVM transaction on both the reference VM and vm2 from the same state,
+    /// reporting any divergence. Requires the `vm2` feature.
+    #[structopt(long = "differential")]
+    pub differential: bool,
+
     /// Whether to dump the debug data for system contracts.
     #[structopt(long = "dump-system")]
     pub dump_system: bool,
@@ -86,6 +163,13 @@ pub struct Arguments {
     #[structopt(long = "use-upstream-solc")]
     pub use_upstream_solc: bool,
 
+    /// Check the Ethereum tests gas expectations against the measured gas.
+    /// Only applies to the `EVM` target with the upstream `solc` compiler, and only to the `solc`
+    /// versions targeting `Shanghai` by default, that is, from 0.8.20 to 0.8.23, since the native
+    /// EVM executes with the `Shanghai` rules.
+    #[structopt(long = "check-gas")]
+    pub check_gas: bool,
+
     /// Path to the default `solc` binaries download configuration file.
     #[structopt(long = "solc-bin-config-path")]
     pub solc_bin_config_path: Option<PathBuf>,
@@ -102,6 +186,11 @@ pub struct Arguments {
     #[structopt(long = "save-system-contracts")]
     pub system_contracts_save_path: Option<PathBuf>,
 
+    /// Path to the persistent compilation cache directory.
+    /// If set, the `solc` and `vyper` outputs are reused across runs.
+    #[structopt(long = "cache-dir")]
+    pub cache_directory: Option<PathBuf>,
+
     /// Sets the `verify each` option in LLVM.
     #[structopt(long = "llvm-verify-each")]
     pub llvm_verify_each: bool,
@@ -111,8 +200,13 @@ pub struct Arguments {
     pub llvm_debug_logging: bool,
 
     /// Choose between `build` to compile tests only without running them, and `run` to compile and run them.
+    /// The `list` workflow only prints the scheduled tests, cases, and modes.
     #[structopt(long = "workflow", default_value = "run")]
     pub workflow: Workflow,
+
+    /// The `list` workflow output format: `text` or `json`.
+    #[structopt(long = "list-format", default_value = "text")]
+    pub list_format: ListingFormat,
 }
 
 impl Arguments {
@@ -6,6 +6,7 @@ pub(crate) mod arguments;
 
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 use std::time::Instant;
 
 use colored::Colorize;
@@ -32,13 +33,17 @@ fn main() {
 /// The entry point wrapper used for proper error handling.
 ///
 fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
-    println!(
-        "    {} {} v{} (LLVM build {})",
-        "Starting".bright_green().bold(),
-        env!("CARGO_PKG_DESCRIPTION"),
-        env!("CARGO_PKG_VERSION"),
-        inkwell::support::get_commit_id().to_string(),
-    );
+    let is_listing = matches!(arguments.workflow, compiler_tester::Workflow::List);
+
+    if !arguments.worker && !is_listing {
+        println!(
+            "    {} {} v{} (LLVM build {})",
+            "Starting".bright_green().bold(),
+            env!("CARGO_PKG_DESCRIPTION"),
+            env!("CARGO_PKG_VERSION"),
+            inkwell::support::get_commit_id().to_string(),
+        );
+    }
 
     inkwell::support::enable_llvm_pretty_stack_trace();
     for target in [
@@ -53,6 +58,16 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         arguments.llvm_verify_each,
         arguments.llvm_debug_logging,
     )?;
+    compiler_tester::Limits::new(
+        arguments.cycles_limit,
+        arguments.ergs_limit,
+        arguments.gas_limit,
+        arguments.timeout.map(Duration::from_secs),
+    )
+    .initialize()?;
+    if let Some(cache_directory) = arguments.cache_directory {
+        compiler_tester::PersistentCache::initialize(cache_directory)?;
+    }
 
     era_compiler_solidity::EXECUTABLE
         .set(
@@ -91,43 +106,131 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         .build_global()
         .expect("Thread pool configuration failure");
 
-    let summary = compiler_tester::Summary::new(arguments.verbosity, arguments.quiet).wrap();
+    let target = match arguments.target {
+        Some(target) => compiler_tester::Target::from_str(target.as_str())?,
+        None => compiler_tester::Target::EraVM,
+    };
+
+    let baseline = match arguments.baseline {
+        Some(path) => compiler_tester::Baseline::try_from_path(path.as_path(), target)?,
+        None => compiler_tester::Baseline::default(),
+    };
+
+    let summary = compiler_tester::Summary::new(
+        arguments.verbosity,
+        arguments.quiet || arguments.worker || is_listing,
+    )
+    .with_baseline(baseline)
+    .wrap();
+
+    let mut filters =
+        compiler_tester::Filters::new(arguments.paths, arguments.modes, arguments.groups);
+    if let Some(path) = arguments.rerun_from {
+        filters = filters.with_rerun(compiler_tester::Rerun::try_from_report(path.as_path())?);
+    }
+
+    let shard = match (arguments.shard_index, arguments.shard_count) {
+        (Some(index), Some(count)) => Some(compiler_tester::Shard::new(index, count)?),
+        (None, None) => None,
+        _ => anyhow::bail!("The `--shard-index` and `--shard-count` options must be used together"),
+    };
+
+    if arguments.timeout.is_some() && arguments.workers.is_none() {
+        anyhow::bail!(
+            "The `--timeout` option requires `--workers`, as only a worker process can be interrupted"
+        );
+    }
 
-    let filters = compiler_tester::Filters::new(arguments.paths, arguments.modes, arguments.groups);
+    let system_contracts_load_path = arguments.system_contracts_load_path;
+    let mut system_contracts_save_path = arguments.system_contracts_save_path;
+    let mut system_contracts_temporary_path = None;
+    // The parent process builds the system contracts once and passes them to the workers.
+    let isolation = if arguments.worker {
+        system_contracts_save_path = None;
+        Some(compiler_tester::Isolation::Worker)
+    } else if let Some(workers) = arguments.workers {
+        let mut worker_arguments = vec![];
+        if target != compiler_tester::Target::EVM && system_contracts_load_path.is_none() {
+            let path = system_contracts_save_path.get_or_insert_with(|| {
+                let path = std::env::temp_dir().join(format!(
+                    "compiler-tester-system-contracts-{}.bin",
+                    std::process::id()
+                ));
+                system_contracts_temporary_path = Some(path.clone());
+                path
+            });
+            worker_arguments.push("--load-system-contracts".to_owned());
+            worker_arguments.push(path.to_string_lossy().into_owned());
+        }
+        Some(compiler_tester::Isolation::Parent {
+            workers,
+            arguments: worker_arguments,
+        })
+    } else {
+        None
+    };
+
+    let mut corpora = arguments.corpora;
+    if let Some(path) = arguments.corpora_config {
+        corpora.extend(compiler_tester::Corpus::try_from_path(path.as_path())?);
+    }
 
     let compiler_tester = compiler_tester::CompilerTester::new(
         summary.clone(),
         filters,
+        shard,
         debug_config.clone(),
         arguments.workflow,
-    )?;
+        isolation,
+    )?
+    .with_corpora(corpora);
+
+    if is_listing {
+        let use_upstream_solc =
+            arguments.use_upstream_solc && target != compiler_tester::Target::EraVM;
+        let listing = compiler_tester.list(target, use_upstream_solc)?;
+        print!("{}", listing.serialize(arguments.list_format));
+        return Ok(());
+    }
 
-    let binary_download_config_paths = vec![
-        arguments.solc_bin_config_path.unwrap_or_else(|| {
-            PathBuf::from(if arguments.use_upstream_solc {
-                "./configs/solc-bin-upstream.json"
-            } else {
-                "./configs/solc-bin-default.json"
-            })
-        }),
-        arguments
-            .vyper_bin_config_path
-            .unwrap_or_else(|| PathBuf::from("./configs/vyper-bin-default.json")),
-    ];
+    // The worker processes reuse the binaries downloaded by the parent process.
+    let binary_download_config_paths = if arguments.worker {
+        vec![]
+    } else {
+        vec![
+            arguments.solc_bin_config_path.unwrap_or_else(|| {
+                PathBuf::from(if arguments.use_upstream_solc {
+                    "./configs/solc-bin-upstream.json"
+                } else {
+                    "./configs/solc-bin-default.json"
+                })
+            }),
+            arguments
+                .vyper_bin_config_path
+                .unwrap_or_else(|| PathBuf::from("./configs/vyper-bin-default.json")),
+        ]
+    };
 
     let run_time_start = Instant::now();
-    println!(
-        "     {} tests with {} worker threads",
-        "Running".bright_green().bold(),
-        rayon::current_num_threads(),
-    );
+    if let Some(workers) = arguments.workers.filter(|_| !arguments.worker) {
+        println!(
+            "     {} tests with {} worker processes",
+            "Running".bright_green().bold(),
+            workers,
+        );
+    } else if !arguments.worker {
+        println!(
+            "     {} tests with {} worker threads",
+            "Running".bright_green().bold(),
+            rayon::current_num_threads(),
+        );
+    }
 
-    let target = match arguments.target {
-        Some(target) => compiler_tester::Target::from_str(target.as_str())?,
-        None => compiler_tester::Target::EraVM,
-    };
+    if arguments.differential && cfg!(not(feature = "vm2")) {
+        anyhow::bail!("The differential mode requires the `vm2` feature");
+    }
 
-    match target {
+    let result = match target {
         compiler_tester::Target::EraVM => {
             zkevm_tester::runners::compiler_tests::set_tracing_mode(
                 zkevm_tester::runners::compiler_tests::VmTracingOptions::from_u64(
@@ -136,7 +239,11 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
             );
 
             #[cfg(feature = "vm2")]
-            zkevm_assembly::set_encoding_mode(zkevm_assembly::RunningVmEncodingMode::Production);
+            zkevm_assembly::set_encoding_mode(if arguments.differential {
+                zkevm_assembly::RunningVmEncodingMode::Testing
+            } else {
+                zkevm_assembly::RunningVmEncodingMode::Production
+            });
             #[cfg(not(feature = "vm2"))]
             zkevm_assembly::set_encoding_mode(zkevm_assembly::RunningVmEncodingMode::Testing);
 
@@ -145,13 +252,14 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
             } else {
                 None
             };
-            let vm = compiler_tester::EraVM::new(
+            let mut vm = compiler_tester::EraVM::new(
                 binary_download_config_paths,
                 PathBuf::from("./configs/solc-bin-system-contracts.json"),
                 system_contracts_debug_config,
-                arguments.system_contracts_load_path,
-                arguments.system_contracts_save_path,
+                system_contracts_load_path,
+                system_contracts_save_path,
             )?;
+            vm.set_differential(arguments.differential);
 
             match (
                 arguments.disable_deployer,
@@ -170,8 +278,10 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
             }
         }
         compiler_tester::Target::EVM => {
-            compiler_tester::EVM::download(binary_download_config_paths)?;
-            compiler_tester.run_evm(arguments.use_upstream_solc)
+            if !arguments.worker {
+                compiler_tester::EVM::download(binary_download_config_paths)?;
+            }
+            compiler_tester.run_evm(arguments.use_upstream_solc, arguments.check_gas)
         }
         compiler_tester::Target::EVMInterpreter => {
             zkevm_tester::runners::compiler_tests::set_tracing_mode(
@@ -186,13 +296,14 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
             } else {
                 None
             };
-            let vm = compiler_tester::EraVM::new(
+            let mut vm = compiler_tester::EraVM::new(
                 binary_download_config_paths,
                 PathBuf::from("./configs/solc-bin-system-contracts.json"),
                 system_contract_debug_config,
-                arguments.system_contracts_load_path,
-                arguments.system_contracts_save_path,
+                system_contracts_load_path,
+                system_contracts_save_path,
             )?;
+            vm.set_differential(arguments.differential);
 
             compiler_tester
                 .run_evm_interpreter::<compiler_tester::EraVMSystemContractDeployer, false>(
@@ -200,9 +311,18 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
                     arguments.use_upstream_solc,
                 )
         }
-    }?;
+    };
+    if let Some(path) = system_contracts_temporary_path {
+        let _ = std::fs::remove_file(path);
+    }
+    result?;
+
+    if arguments.worker {
+        return Ok(());
+    }
 
     let summary = compiler_tester::Summary::unwrap_arc(summary);
+    summary.print_xpassed();
     print!("{summary}");
     println!(
         "    {} running tests in {}m{:02}s",
@@ -216,6 +336,11 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         benchmark.write_to_file(path)?;
     }
 
+    if let Some(path) = arguments.report {
+        let report = summary.report();
+        report.write_to_file(path, arguments.report_format)?;
+    }
+
     if !summary.is_successful() {
         anyhow::bail!("");
     }
@@ -242,11 +367,26 @@ mod tests {
             quiet: false,
             debug: false,
             trace: 2,
-            modes: vec!["Y+M3B3 0.8.24".to_owned()],
+            modes: vec!["Y+M3B3 0.8.24".parse().expect("Always valid")],
             paths: vec!["tests/solidity/simple/default.sol".to_owned()],
             groups: vec![],
+            corpora: vec![],
+            corpora_config: None,
+            rerun_from: None,
+            shard_index: None,
+            shard_count: None,
             benchmark: None,
+            report: None,
+            report_format: compiler_tester::ReportFormat::JSONLines,
+            baseline: None,
             threads: Some(1),
+            workers: None,
+            worker: false,
+            cycles_limit: None,
+            ergs_limit: None,
+            gas_limit: None,
+            timeout: None,
+            differential: false,
             dump_system: false,
             disable_deployer: false,
             disable_value_simulator: false,
@@ -256,13 +396,16 @@ mod tests {
             zkvyper: Some(PathBuf::from(era_compiler_vyper::DEFAULT_EXECUTABLE_NAME)),
             target: Some(compiler_tester::Target::EraVM.to_string()),
             use_upstream_solc: false,
+            check_gas: false,
             solc_bin_config_path: Some(PathBuf::from("./configs/solc-bin-default.json")),
             vyper_bin_config_path: Some(PathBuf::from("./configs/vyper-bin-default.json")),
             system_contracts_load_path: Some(PathBuf::from("system-contracts-stable-build")),
             system_contracts_save_path: None,
+            cache_directory: None,
             llvm_verify_each: false,
             llvm_debug_logging: false,
             workflow: compiler_tester::Workflow::BuildAndRun,
+            list_format: compiler_tester::ListingFormat::Text,
         };
 
         crate::main_inner(arguments).expect("Manual testing failed");
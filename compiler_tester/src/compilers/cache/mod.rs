@@ -2,6 +2,7 @@
 //! The thread-safe cache implementation.
 //!
 
+pub mod persistent;
 pub mod value;
 
 use std::collections::HashMap;
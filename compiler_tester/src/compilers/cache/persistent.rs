@@ -0,0 +1,202 @@
+//!
+//! The persistent on-disk compilation cache.
+//!
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use sha3::Digest;
+
+/// The one-time initialization cell for the global persistent cache.
+static PERSISTENT_CACHE: once_cell::sync::OnceCell<PersistentCache> =
+    once_cell::sync::OnceCell::new();
+
+/// The temporary file counter to avoid collisions between concurrent writers.
+static TEMPORARY_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// The persistent on-disk compilation cache.
+///
+/// Entries are stored as JSON files named after the hash of their key components,
+/// which include the source code, the compiler binary contents, and the compilation mode.
+/// Unreadable or stale entries are treated as misses and overwritten.
+///
+#[derive(Debug)]
+pub struct PersistentCache {
+    /// The cache directory.
+    directory: PathBuf,
+    /// The compiler binary hashes, computed once per executable.
+    /// The map is only locked to get the cell, so hashing one binary does not block the others.
+    binary_hashes: Mutex<HashMap<PathBuf, Arc<once_cell::sync::OnceCell<String>>>>,
+}
+
+impl PersistentCache {
+    /// The cache entry format version, which must be bumped on incompatible changes.
+    const FORMAT_VERSION: &'static str = "1";
+
+    ///
+    /// Initializes the global persistent cache in the specified directory.
+    ///
+    pub fn initialize(directory: PathBuf) -> anyhow::Result<()> {
+        std::fs::create_dir_all(directory.as_path()).map_err(|error| {
+            anyhow::anyhow!("Cache directory {:?} creating error: {}", directory, error)
+        })?;
+        PERSISTENT_CACHE
+            .set(Self {
+                directory,
+                binary_hashes: Mutex::new(HashMap::new()),
+            })
+            .map_err(|_| anyhow::anyhow!("The persistent cache is already initialized"))?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the global persistent cache, if it has been initialized.
+    ///
+    pub fn get() -> Option<&'static Self> {
+        PERSISTENT_CACHE.get()
+    }
+
+    ///
+    /// Loads the value from the persistent cache if it is available, or evaluates and stores it.
+    ///
+    /// Falls back to plain evaluation if the persistent cache is not initialized.
+    /// Errors are never cached, so failed compilations are retried on the next run.
+    /// Failed writes are ignored like failed loads, so the value is evaluated again next time.
+    ///
+    pub fn load_or_evaluate<V, F>(
+        namespace: &str,
+        executable: &Path,
+        key: &[&[u8]],
+        f: F,
+    ) -> anyhow::Result<V>
+    where
+        V: serde::Serialize + serde::de::DeserializeOwned,
+        F: FnOnce() -> anyhow::Result<V>,
+    {
+        let cache = match Self::get() {
+            Some(cache) => cache,
+            None => return f(),
+        };
+
+        let path = match cache.entry_path(namespace, executable, key) {
+            Ok(path) => path,
+            Err(_) => return f(),
+        };
+
+        if let Some(value) = Self::load(path.as_path()) {
+            return Ok(value);
+        }
+
+        let value = f()?;
+        let _ = Self::store(path.as_path(), &value);
+        Ok(value)
+    }
+
+    ///
+    /// Returns the cache entry path for the specified key components.
+    ///
+    fn entry_path(
+        &self,
+        namespace: &str,
+        executable: &Path,
+        key: &[&[u8]],
+    ) -> anyhow::Result<PathBuf> {
+        let binary_hash = self.binary_hash(executable)?;
+
+        let mut hasher = sha3::Keccak256::new();
+        for component in [
+            Self::FORMAT_VERSION.as_bytes(),
+            env!("CARGO_PKG_VERSION").as_bytes(),
+            namespace.as_bytes(),
+            binary_hash.as_bytes(),
+        ]
+        .into_iter()
+        .chain(key.iter().copied())
+        {
+            hasher.update((component.len() as u64).to_be_bytes());
+            hasher.update(component);
+        }
+        let hash = hex::encode(hasher.finalize());
+
+        let mut path = self.directory.clone();
+        path.push(namespace);
+        path.push(format!("{hash}.json"));
+        Ok(path)
+    }
+
+    ///
+    /// Returns the hash of the compiler binary contents.
+    ///
+    fn binary_hash(&self, executable: &Path) -> anyhow::Result<String> {
+        let executable = which::which(executable).unwrap_or_else(|_| executable.to_path_buf());
+
+        let cell = self
+            .binary_hashes
+            .lock()
+            .expect("Sync")
+            .entry(executable.clone())
+            .or_default()
+            .clone();
+        cell.get_or_try_init(|| -> anyhow::Result<String> {
+            let contents = std::fs::read(executable.as_path()).map_err(|error| {
+                anyhow::anyhow!("Compiler binary {:?} reading error: {}", executable, error)
+            })?;
+            Ok(hex::encode(sha3::Keccak256::digest(contents.as_slice())))
+        })
+        .cloned()
+    }
+
+    ///
+    /// Loads a cache entry, removing it if it cannot be read.
+    ///
+    fn load<V>(path: &Path) -> Option<V>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        let contents = std::fs::read(path).ok()?;
+        match serde_json::from_slice(contents.as_slice()) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                let _ = std::fs::remove_file(path);
+                None
+            }
+        }
+    }
+
+    ///
+    /// Stores a cache entry atomically via a temporary file.
+    ///
+    fn store<V>(path: &Path, value: &V) -> anyhow::Result<()>
+    where
+        V: serde::Serialize,
+    {
+        let directory = path.parent().expect("Always exists");
+        std::fs::create_dir_all(directory)?;
+
+        let mut temporary_path = path.to_path_buf();
+        temporary_path.set_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = std::fs::File::create(temporary_path.as_path())
+            .map_err(anyhow::Error::from)
+            .and_then(|mut file| {
+                serde_json::to_writer(&mut file, value)?;
+                file.flush()?;
+                std::fs::rename(temporary_path.as_path(), path)?;
+                Ok(())
+            });
+        if result.is_err() {
+            let _ = std::fs::remove_file(temporary_path.as_path());
+        }
+        result
+    }
+}
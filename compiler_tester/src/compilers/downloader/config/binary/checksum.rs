@@ -0,0 +1,56 @@
+//!
+//! The compiler downloader binary SHA-256 checksum.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use sha2::Digest;
+
+///
+/// The compiler downloader binary SHA-256 checksum.
+///
+/// Either a single hexadecimal hash, or a map of hashes keyed by the `${PLATFORM}` value.
+///
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Checksum {
+    /// The platform-independent checksum.
+    Single(String),
+    /// The checksums for each platform.
+    PerPlatform(BTreeMap<String, String>),
+}
+
+impl Checksum {
+    ///
+    /// Returns the expected checksum for the specified platform.
+    ///
+    pub fn get(&self, platform: &str) -> Option<&str> {
+        match self {
+            Self::Single(checksum) => Some(checksum.as_str()),
+            Self::PerPlatform(checksums) => {
+                checksums.get(platform).map(|checksum| checksum.as_str())
+            }
+        }
+    }
+
+    ///
+    /// Checks whether the SHA-256 of `data` matches the `expected` hexadecimal hash.
+    ///
+    pub fn verify(name: &str, data: &[u8], expected: &str) -> anyhow::Result<()> {
+        let expected = expected
+            .strip_prefix("0x")
+            .unwrap_or(expected)
+            .to_ascii_lowercase();
+        let found = hex::encode(sha2::Sha256::digest(data));
+        if found != expected {
+            anyhow::bail!(
+                "Binary `{}` SHA-256 checksum mismatch: expected `{}`, found `{}`",
+                name,
+                expected,
+                found
+            );
+        }
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@
 //! The compiler downloader binary config.
 //!
 
+pub mod checksum;
 pub mod protocol;
 
 use serde::Deserialize;
 
+use self::checksum::Checksum;
 use self::protocol::Protocol;
 
 ///
@@ -21,4 +23,7 @@ pub struct Binary {
     pub source: String,
     /// The downloaded binary file destination.
     pub destination: String,
+    /// The expected SHA-256 checksum of the binary.
+    #[serde(default)]
+    pub sha256: Option<Checksum>,
 }
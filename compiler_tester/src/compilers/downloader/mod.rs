@@ -12,6 +12,7 @@ use std::str::FromStr;
 
 use colored::Colorize;
 
+use self::config::binary::checksum::Checksum;
 use self::config::binary::protocol::Protocol;
 use self::config::Config;
 use self::solc_list::SolcList;
@@ -75,9 +76,16 @@ impl Downloader {
                 anyhow::anyhow!("Binary `{}` destination is invalid", destination_path)
             })?;
 
+            let mut expected_sha256 = binary
+                .sha256
+                .as_ref()
+                .and_then(|checksum| checksum.get(platform_directory.as_str()))
+                .map(|checksum| checksum.to_owned());
+
             let data = match binary.protocol {
                 Protocol::File => {
                     if source_path == destination_path.to_string_lossy() {
+                        Self::verify_file(destination_path.as_path(), expected_sha256.as_deref())?;
                         continue;
                     }
 
@@ -88,19 +96,17 @@ impl Downloader {
                         destination_path,
                     );
 
-                    std::fs::copy(source_path.as_str(), binary.destination.as_str()).map_err(
-                        |error| {
-                            anyhow::anyhow!(
-                                "Binary {:?} copying error: {}",
-                                source_path.as_str(),
-                                error
-                            )
-                        },
-                    )?;
-                    continue;
+                    std::fs::read(source_path.as_str()).map_err(|error| {
+                        anyhow::anyhow!(
+                            "Binary {:?} copying error: {}",
+                            source_path.as_str(),
+                            error
+                        )
+                    })?
                 }
                 Protocol::HTTPS => {
                     if destination_path.exists() {
+                        Self::verify_file(destination_path.as_path(), expected_sha256.as_deref())?;
                         continue;
                     }
 
@@ -112,18 +118,19 @@ impl Downloader {
                         source_url,
                         destination_path,
                     );
-                    self.http_client.get(source_url).send()?.bytes()?
+                    self.http_client.get(source_url).send()?.bytes()?.to_vec()
                 }
                 Protocol::SolcBinList => {
-                    if destination_path.exists() {
+                    if destination_path.exists() && expected_sha256.is_some() {
+                        Self::verify_file(destination_path.as_path(), expected_sha256.as_deref())?;
                         continue;
                     }
 
                     let solc_list_path = PathBuf::from(source_path.as_str());
-                    let solc_list = self.solc_list.get_or_insert_with(|| {
-                        SolcList::try_from(solc_list_path.as_path())
-                            .expect("solc-bin JSON list downloading error")
-                    });
+                    if self.solc_list.is_none() {
+                        self.solc_list = Some(SolcList::try_from(solc_list_path.as_path())?);
+                    }
+                    let solc_list = self.solc_list.as_ref().expect("Always exists");
                     if solc_list.releases.is_empty() {
                         return Ok(config);
                     }
@@ -136,6 +143,17 @@ impl Downloader {
                                 version
                             ),
                         };
+                    if expected_sha256.is_none() {
+                        expected_sha256 = solc_list
+                            .sha256(source_binary_name.as_str())
+                            .map(|checksum| checksum.to_owned());
+                    }
+
+                    if destination_path.exists() {
+                        Self::verify_file(destination_path.as_path(), expected_sha256.as_deref())?;
+                        continue;
+                    }
+
                     let mut source_path = solc_list_path;
                     source_path.pop();
                     source_path.push(source_binary_name);
@@ -149,10 +167,14 @@ impl Downloader {
                         source_url,
                         destination_path,
                     );
-                    self.http_client.get(source_url).send()?.bytes()?
+                    self.http_client.get(source_url).send()?.bytes()?.to_vec()
                 }
             };
 
+            if let Some(expected_sha256) = expected_sha256.as_deref() {
+                Checksum::verify(source_path.as_str(), data.as_slice(), expected_sha256)?;
+            }
+
             let mut destination_folder = destination_path.clone();
             destination_folder.pop();
             std::fs::create_dir_all(destination_folder)?;
@@ -163,4 +185,152 @@ impl Downloader {
 
         Ok(config)
     }
+
+    ///
+    /// Checks the checksum of an existing binary file, if the checksum is specified.
+    ///
+    fn verify_file(path: &Path, expected_sha256: Option<&str>) -> anyhow::Result<()> {
+        let expected_sha256 = match expected_sha256 {
+            Some(expected_sha256) => expected_sha256,
+            None => return Ok(()),
+        };
+
+        let data = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("Binary {:?} reading error: {}", path, error))?;
+        Checksum::verify(
+            path.to_string_lossy().as_ref(),
+            data.as_slice(),
+            expected_sha256,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use sha2::Digest;
+
+    use super::Downloader;
+
+    const BINARY: &[u8] = b"#!/bin/sh\necho 'solc, the solidity compiler'\n";
+
+    ///
+    /// The temporary directory, which is removed with its contents on drop.
+    ///
+    struct TemporaryDirectory {
+        /// The directory path.
+        path: PathBuf,
+    }
+
+    impl Drop for TemporaryDirectory {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(self.path.as_path());
+        }
+    }
+
+    fn directory(name: &str) -> TemporaryDirectory {
+        let mut path = std::env::temp_dir();
+        path.push(format!(
+            "compiler-tester-downloader-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(path.as_path());
+        std::fs::create_dir_all(path.as_path()).expect("Always valid");
+        TemporaryDirectory { path }
+    }
+
+    fn download(
+        directory: &PathBuf,
+        protocol: &str,
+        source: &PathBuf,
+        sha256: &str,
+    ) -> anyhow::Result<()> {
+        let mut destination = directory.clone();
+        destination.push("solc-${VERSION}");
+        let config = serde_json::json!({
+            "binaries": {
+                "0.8.24": {
+                    "is_enabled": true,
+                    "protocol": protocol,
+                    "source": source,
+                    "destination": destination,
+                    "sha256": sha256,
+                }
+            },
+            "platforms": {
+                "linux-amd64": "linux-amd64",
+                "linux-arm64": "linux-arm64",
+                "macos-amd64": "macosx-amd64",
+                "macos-arm64": "macosx-amd64",
+            }
+        });
+        let mut config_path = directory.clone();
+        config_path.push("config.json");
+        std::fs::write(config_path.as_path(), config.to_string()).expect("Always valid");
+
+        Downloader::new(reqwest::blocking::Client::new())
+            .download(config_path.as_path())
+            .map(|_| ())
+    }
+
+    #[test]
+    fn good_checksum() {
+        let directory = directory("good");
+        let mut source = directory.path.clone();
+        source.push("source");
+        std::fs::write(source.as_path(), BINARY).expect("Always valid");
+
+        let sha256 = hex::encode(sha2::Sha256::digest(BINARY));
+        download(&directory.path, "file", &source, sha256.as_str()).expect("Always valid");
+
+        let mut destination = directory.path.clone();
+        destination.push("solc-0.8.24");
+        assert_eq!(
+            std::fs::read(destination.as_path()).expect("Always valid"),
+            BINARY
+        );
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let directory = directory("bad");
+        let mut source = directory.path.clone();
+        source.push("source");
+        std::fs::write(source.as_path(), BINARY).expect("Always valid");
+
+        let sha256 = hex::encode(sha2::Sha256::digest(b"tampered"));
+        let result = download(&directory.path, "file", &source, sha256.as_str());
+        assert!(result.is_err());
+
+        let mut destination = directory.path.clone();
+        destination.push("solc-0.8.24");
+        assert!(!destination.exists());
+    }
+
+    #[test]
+    fn truncated_file() {
+        let directory = directory("truncated");
+        let mut source = directory.path.clone();
+        source.push("source");
+        std::fs::write(source.as_path(), &BINARY[..BINARY.len() / 2]).expect("Always valid");
+
+        let sha256 = hex::encode(sha2::Sha256::digest(BINARY));
+        let result = download(&directory.path, "file", &source, sha256.as_str());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn truncated_reused_file() {
+        let directory = directory("reused");
+        let mut destination = directory.path.clone();
+        destination.push("solc-0.8.24");
+        std::fs::write(destination.as_path(), &BINARY[..BINARY.len() / 2]).expect("Always valid");
+
+        let source = PathBuf::from("https://localhost/solc-0.8.24");
+        let sha256 = hex::encode(sha2::Sha256::digest(BINARY));
+        let result = download(&directory.path, "https", &source, sha256.as_str());
+        assert!(result.is_err());
+    }
 }
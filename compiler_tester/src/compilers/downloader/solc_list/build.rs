@@ -0,0 +1,19 @@
+//!
+//! The Solidity compiler JSON list build metadata.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Solidity compiler JSON list build metadata.
+///
+#[derive(Debug, Deserialize)]
+pub struct Build {
+    /// The binary file name.
+    pub path: String,
+    /// The compiler version.
+    pub version: String,
+    /// The SHA-256 checksum of the binary.
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
@@ -2,6 +2,8 @@
 //! The Solidity compiler JSON list metadata.
 //!
 
+pub mod build;
+
 use std::collections::BTreeMap;
 use std::path::Path;
 use std::str::FromStr;
@@ -9,6 +11,8 @@ use std::str::FromStr;
 use colored::Colorize;
 use serde::Deserialize;
 
+use self::build::Build;
+
 ///
 /// The Solidity compiler JSON list metadata.
 ///
@@ -16,6 +20,21 @@ use serde::Deserialize;
 pub struct SolcList {
     /// The collection of compiler releases.
     pub releases: BTreeMap<String, String>,
+    /// The collection of compiler builds.
+    #[serde(default)]
+    pub builds: Vec<Build>,
+}
+
+impl SolcList {
+    ///
+    /// Returns the SHA-256 checksum of the binary with the specified file name, if listed.
+    ///
+    pub fn sha256(&self, path: &str) -> Option<&str> {
+        self.builds
+            .iter()
+            .find(|build| build.path == path)
+            .and_then(|build| build.sha256.as_deref())
+    }
 }
 
 impl TryFrom<&Path> for SolcList {
@@ -0,0 +1,236 @@
+//!
+//! The compiler mode descriptor.
+//!
+
+use std::str::FromStr;
+
+///
+/// The compiler mode descriptor.
+///
+/// It is the parsed representation of the mode string, e.g. `Y+M3B3 0.8.24`,
+/// and is printed back to exactly the same string.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Descriptor {
+    /// The source code pipeline: `Y`, `y`, `E`, or `V`.
+    pub pipeline: Option<char>,
+    /// Whether the source code compiler optimizer is enabled.
+    pub optimize: Option<bool>,
+    /// The LLVM middle-end optimization level: `0`, `1`, `2`, `3`, `s`, or `z`.
+    pub level_middle_end: Option<char>,
+    /// The LLVM back-end optimization level: `0`, `1`, `2`, or `3`.
+    pub level_back_end: Option<char>,
+    /// The source code compiler version.
+    pub version: Option<semver::Version>,
+}
+
+impl Descriptor {
+    /// The allowed pipeline characters.
+    pub const PIPELINES: [char; 4] = ['Y', 'y', 'E', 'V'];
+
+    /// The allowed LLVM middle-end optimization levels.
+    pub const LEVELS_MIDDLE_END: [char; 6] = ['0', '1', '2', '3', 's', 'z'];
+
+    /// The allowed LLVM back-end optimization levels.
+    pub const LEVELS_BACK_END: [char; 4] = ['0', '1', '2', '3'];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The LLVM levels are taken from the `M<level>B<level>` representation of the optimizer settings.
+    ///
+    pub fn new(
+        pipeline: Option<char>,
+        optimize: Option<bool>,
+        llvm_optimizer_settings: Option<&era_compiler_llvm_context::OptimizerSettings>,
+        version: Option<semver::Version>,
+    ) -> Self {
+        let (level_middle_end, level_back_end) = match llvm_optimizer_settings
+            .map(|settings| settings.to_string().chars().take(4).collect::<Vec<char>>())
+            .as_deref()
+        {
+            Some(['M', level_middle_end, 'B', level_back_end]) => {
+                (Some(*level_middle_end), Some(*level_back_end))
+            }
+            _ => (None, None),
+        };
+
+        Self {
+            pipeline,
+            optimize,
+            level_middle_end,
+            level_back_end,
+            version,
+        }
+    }
+
+    ///
+    /// Returns the pipeline character of the Solidity compiler pipeline.
+    ///
+    pub fn solidity_pipeline(pipeline: era_compiler_solidity::SolcPipeline, via_ir: bool) -> char {
+        match pipeline {
+            era_compiler_solidity::SolcPipeline::Yul => 'Y',
+            era_compiler_solidity::SolcPipeline::EVMLA if via_ir => 'y',
+            era_compiler_solidity::SolcPipeline::EVMLA => 'E',
+        }
+    }
+}
+
+impl FromStr for Descriptor {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (code, version) = match string.split_once(' ') {
+            Some((code, version)) => (code, Some(version)),
+            None => (string, None),
+        };
+
+        let mut chars = code.chars().peekable();
+
+        let mut pipeline = None;
+        let mut optimize = None;
+        if let Some(character) = chars.next_if(|character| Self::PIPELINES.contains(character)) {
+            pipeline = Some(character);
+            optimize = match chars.next() {
+                Some('+') => Some(true),
+                Some('-') => Some(false),
+                _ => anyhow::bail!("Mode `{string}` has no optimization flag after `{character}`"),
+            };
+        }
+
+        let mut level_middle_end = None;
+        let mut level_back_end = None;
+        if chars.next_if_eq(&'M').is_some() {
+            level_middle_end =
+                match chars.next_if(|character| Self::LEVELS_MIDDLE_END.contains(character)) {
+                    Some(level) => Some(level),
+                    None => anyhow::bail!("Mode `{string}` has an invalid middle-end level"),
+                };
+            if chars.next_if_eq(&'B').is_none() {
+                anyhow::bail!("Mode `{string}` has no back-end level");
+            }
+            level_back_end =
+                match chars.next_if(|character| Self::LEVELS_BACK_END.contains(character)) {
+                    Some(level) => Some(level),
+                    None => anyhow::bail!("Mode `{string}` has an invalid back-end level"),
+                };
+        }
+
+        if let Some(character) = chars.next() {
+            anyhow::bail!("Mode `{string}` has an unexpected character `{character}`");
+        }
+
+        let version = match version {
+            Some(version) => Some(
+                semver::Version::parse(version)
+                    .map_err(|error| anyhow::anyhow!("Mode `{string}` version: {error}"))?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            pipeline,
+            optimize,
+            level_middle_end,
+            level_back_end,
+            version,
+        })
+    }
+}
+
+impl std::fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(pipeline) = self.pipeline {
+            write!(f, "{pipeline}")?;
+        }
+        match self.optimize {
+            Some(true) => write!(f, "+")?,
+            Some(false) => write!(f, "-")?,
+            None => {}
+        }
+        if let (Some(level_middle_end), Some(level_back_end)) =
+            (self.level_middle_end, self.level_back_end)
+        {
+            write!(f, "M{level_middle_end}B{level_back_end}")?;
+        }
+        if let Some(version) = self.version.as_ref() {
+            write!(f, " {version}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Descriptor;
+
+    #[test]
+    fn round_trip() {
+        for string in [
+            "Y+M3B3 0.8.24",
+            "y-M0B0 0.8.24",
+            "E+MzB3 0.4.26",
+            "V-MsB2 0.3.10",
+            "Y+ 0.8.24",
+            "M1B1",
+            "",
+        ] {
+            let descriptor = Descriptor::from_str(string).expect("Always valid");
+            assert_eq!(descriptor.to_string(), string);
+        }
+    }
+
+    #[test]
+    fn components() {
+        let descriptor = Descriptor::from_str("E-M2B1 0.8.1").expect("Always valid");
+
+        assert_eq!(descriptor.pipeline, Some('E'));
+        assert_eq!(descriptor.optimize, Some(false));
+        assert_eq!(descriptor.level_middle_end, Some('2'));
+        assert_eq!(descriptor.level_back_end, Some('1'));
+        assert_eq!(descriptor.version, Some(semver::Version::new(0, 8, 1)));
+    }
+
+    #[test]
+    fn new() {
+        let descriptor = Descriptor::new(
+            Some(Descriptor::solidity_pipeline(
+                era_compiler_solidity::SolcPipeline::EVMLA,
+                true,
+            )),
+            Some(true),
+            Some(&era_compiler_llvm_context::OptimizerSettings::cycles()),
+            Some(semver::Version::new(0, 8, 24)),
+        );
+
+        assert_eq!(descriptor.to_string(), "y+M3B3 0.8.24");
+    }
+
+    #[test]
+    fn error_no_optimization_flag() {
+        assert!(Descriptor::from_str("Y 0.8.24").is_err());
+    }
+
+    #[test]
+    fn error_no_back_end_level() {
+        assert!(Descriptor::from_str("Y+M3 0.8.24").is_err());
+    }
+
+    #[test]
+    fn error_invalid_level() {
+        assert!(Descriptor::from_str("Y+M4B3 0.8.24").is_err());
+        assert!(Descriptor::from_str("Y+M3Bz 0.8.24").is_err());
+    }
+
+    #[test]
+    fn error_unexpected_character() {
+        assert!(Descriptor::from_str("Y+M3B3! 0.8.24").is_err());
+    }
+
+    #[test]
+    fn error_invalid_version() {
+        assert!(Descriptor::from_str("Y+M3B3 0.8").is_err());
+    }
+}
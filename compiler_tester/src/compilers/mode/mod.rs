@@ -2,9 +2,9 @@
 //! The compiler mode.
 //!
 
+pub mod descriptor;
 pub mod llvm_options;
-
-use std::collections::HashSet;
+pub mod specification;
 
 use crate::compilers::eravm::mode::Mode as EraVMMode;
 use crate::compilers::llvm::mode::Mode as LLVMMode;
@@ -13,6 +13,9 @@ use crate::compilers::solidity::upstream::mode::Mode as SolidityUpstreamMode;
 use crate::compilers::vyper::mode::Mode as VyperMode;
 use crate::compilers::yul::mode::Mode as YulMode;
 
+use self::descriptor::Descriptor;
+use self::specification::Specification;
+
 ///
 /// The compiler mode.
 ///
@@ -34,6 +37,20 @@ pub enum Mode {
 }
 
 impl Mode {
+    ///
+    /// Returns the name of the compiler the mode belongs to.
+    ///
+    pub fn compiler_name(&self) -> &'static str {
+        match self {
+            Self::Solidity(_) => "Solidity",
+            Self::SolidityUpstream(_) => "SolidityUpstream",
+            Self::Yul(_) => "Yul",
+            Self::Vyper(_) => "Vyper",
+            Self::LLVM(_) => "LLVM",
+            Self::EraVM(_) => "EraVM",
+        }
+    }
+
     ///
     /// Sets the system mode if applicable.
     ///
@@ -46,42 +63,41 @@ impl Mode {
     }
 
     ///
-    /// Checks if the mode is compatible with the filters.
-    ///
-    pub fn check_filters(&self, filters: &HashSet<String>) -> bool {
-        filters.is_empty()
-            || filters
-                .iter()
-                .any(|filter| self.normalize(filter).contains(filter))
+    /// Returns the structured representation of the mode, which is printed as the mode string.
+    ///
+    pub fn descriptor(&self) -> Descriptor {
+        let (pipeline, optimize, version) = match self {
+            Self::Solidity(mode) => (
+                Some(Descriptor::solidity_pipeline(
+                    mode.solc_pipeline,
+                    mode.via_ir,
+                )),
+                Some(mode.solc_optimize),
+                Some(mode.solc_version.to_owned()),
+            ),
+            Self::SolidityUpstream(mode) => (
+                Some(Descriptor::solidity_pipeline(
+                    mode.solc_pipeline,
+                    mode.via_ir,
+                )),
+                Some(mode.solc_optimize),
+                Some(mode.solc_version.to_owned()),
+            ),
+            Self::Vyper(mode) => (
+                Some('V'),
+                Some(mode.vyper_optimize),
+                Some(mode.vyper_version.to_owned()),
+            ),
+            Self::Yul(_) | Self::LLVM(_) | Self::EraVM(_) => (None, None, None),
+        };
+        Descriptor::new(pipeline, optimize, self.llvm_optimizer_settings(), version)
     }
 
     ///
-    /// Checks if the mode is compatible with the extended filters.
-    /// The extended filter consists of 2 parts: mode substring and version range.
+    /// Checks if the mode is compatible with the filters.
     ///
-    pub fn check_extended_filters(&self, filters: &[String]) -> bool {
-        if filters.is_empty() {
-            return true;
-        }
-        for filter in filters.iter() {
-            let mut split = filter.split_whitespace();
-            let mode_filter = split.next().unwrap_or_default();
-            let normalized_mode = self.normalize(mode_filter);
-            if !normalized_mode.contains(mode_filter) {
-                continue;
-            }
-
-            let version = match split.next() {
-                Some(version) => version,
-                None => return true,
-            };
-            if let Ok(version_req) = semver::VersionReq::parse(version) {
-                if self.check_version(&version_req) {
-                    return true;
-                }
-            }
-        }
-        false
+    pub fn check_filters(&self, filters: &[Specification]) -> bool {
+        filters.is_empty() || Specification::check_all(filters, &self.descriptor())
     }
 
     ///
@@ -120,6 +136,21 @@ impl Mode {
         }
     }
 
+    ///
+    /// Returns the Ethereum test gas expectation matching the mode, if specified.
+    ///
+    /// Only the upstream Solidity compiler modes have gas expectations.
+    ///
+    pub fn expected_gas(
+        &self,
+        gas_options: &[solidity_adapter::GasOption],
+    ) -> Option<web3::types::U256> {
+        match self {
+            Mode::SolidityUpstream(mode) => mode.expected_gas(gas_options),
+            _ => None,
+        }
+    }
+
     ///
     /// Returns the LLVM optimizer settings.
     ///
@@ -133,75 +164,6 @@ impl Mode {
             Mode::EraVM(_mode) => None,
         }
     }
-
-    ///
-    /// Normalizes the mode according to the filter.
-    ///
-    fn normalize(&self, filter: &str) -> String {
-        let mut current = self.to_string();
-        if filter.contains("Y*") {
-            current = regex::Regex::new("Y[-+]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "Y*")
-                .to_string();
-        }
-        if filter.contains("E*") {
-            current = regex::Regex::new("E[-+]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "E*")
-                .to_string();
-        }
-        if filter.contains("y*") {
-            current = regex::Regex::new("y[-+]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "y*")
-                .to_string();
-        }
-        if filter.contains("V*") {
-            current = regex::Regex::new("V[-+]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "V*")
-                .to_string();
-        }
-        if filter.contains("M^") {
-            current = regex::Regex::new("M[3z]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "M^")
-                .to_string();
-        }
-        if filter.contains("M*") {
-            current = regex::Regex::new("M[0123sz]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "M*")
-                .to_string();
-        }
-        if filter.contains("B*") {
-            current = regex::Regex::new("B[0123]")
-                .expect("Always valid")
-                .replace_all(current.as_str(), "B*")
-                .to_string();
-        }
-
-        if filter.starts_with('^') {
-            match self {
-                Self::Solidity(_) | Self::SolidityUpstream(_) | Self::Vyper(_) => {
-                    current = regex::Regex::new("[+]")
-                        .expect("Always valid")
-                        .replace_all(current.as_str(), "^")
-                        .to_string();
-                }
-                Self::Yul(_) | Self::LLVM(_) => {
-                    current = regex::Regex::new(".*M")
-                        .expect("Always valid")
-                        .replace_all(current.as_str(), "^M")
-                        .to_string();
-                }
-                Self::EraVM(_) => {}
-            }
-        }
-
-        current
-    }
 }
 
 impl From<SolidityMode> for Mode {
@@ -0,0 +1,357 @@
+//!
+//! The compiler mode specification.
+//!
+
+use std::iter::Peekable;
+use std::str::Chars;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use super::descriptor::Descriptor;
+
+///
+/// The compiler mode specification.
+///
+/// Matches a set of modes. The grammar is `[!][^][pipeline][optimize][M<levels>][B<levels>] [versions]`, where:
+/// - `!` negates the specification
+/// - `^` requires the optimizer to be enabled: `+` for Solidity and Vyper, `M3` or `Mz` for the rest
+/// - `pipeline` is `Y`, `y`, `E`, `V`, or a set such as `{Y,E}`
+/// - `optimize` is `+`, `-`, or `*` for any
+/// - `levels` is a single level, a set such as `{0,3}`, `*` for any, or `^` for the optimized levels,
+///   which are `{3,z}` in the middle-end and `3` in the back-end
+/// - `versions` is a semver requirement such as `>=0.8.0, <0.8.20`, where a bare version such as `0.8.24` is exact
+///
+/// The omitted components match any mode, and `*` alone matches all modes.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Specification {
+    /// Whether the specification excludes the matched modes.
+    pub is_negated: bool,
+    /// Whether the optimizer must be enabled.
+    pub is_optimized: bool,
+    /// The allowed source code pipelines.
+    pub pipelines: Option<Vec<char>>,
+    /// The required source code compiler optimizer flag.
+    pub optimize: Option<bool>,
+    /// The allowed LLVM middle-end optimization levels.
+    pub levels_middle_end: Option<Vec<char>>,
+    /// The allowed LLVM back-end optimization levels.
+    pub levels_back_end: Option<Vec<char>>,
+    /// The allowed source code compiler versions.
+    pub versions: Option<semver::VersionReq>,
+}
+
+impl Specification {
+    ///
+    /// Checks if the descriptor matches the specification, ignoring the negation.
+    ///
+    pub fn matches(&self, descriptor: &Descriptor) -> bool {
+        if self.is_optimized {
+            let is_optimized = match descriptor.optimize {
+                Some(optimize) => optimize,
+                None => matches!(descriptor.level_middle_end, Some('3' | 'z')),
+            };
+            if !is_optimized {
+                return false;
+            }
+        }
+        if !Self::check_component(self.pipelines.as_deref(), descriptor.pipeline) {
+            return false;
+        }
+        if let Some(optimize) = self.optimize {
+            if descriptor.optimize != Some(optimize) {
+                return false;
+            }
+        }
+        if !Self::check_component(
+            self.levels_middle_end.as_deref(),
+            descriptor.level_middle_end,
+        ) {
+            return false;
+        }
+        if !Self::check_component(self.levels_back_end.as_deref(), descriptor.level_back_end) {
+            return false;
+        }
+        if let Some(versions) = self.versions.as_ref() {
+            match descriptor.version.as_ref() {
+                Some(version) if versions.matches(version) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    ///
+    /// Checks if the descriptor matches any of the non-negated specifications,
+    /// or there are none, and does not match any of the negated ones.
+    ///
+    pub fn check_all(specifications: &[Self], descriptor: &Descriptor) -> bool {
+        let mut has_positive = false;
+        let mut is_positive_matched = false;
+        for specification in specifications.iter() {
+            let is_matched = specification.matches(descriptor);
+            if specification.is_negated {
+                if is_matched {
+                    return false;
+                }
+            } else {
+                has_positive = true;
+                is_positive_matched |= is_matched;
+            }
+        }
+        !has_positive || is_positive_matched
+    }
+
+    ///
+    /// Checks if the mode component is one of the allowed values.
+    ///
+    fn check_component(allowed: Option<&[char]>, actual: Option<char>) -> bool {
+        match (allowed, actual) {
+            (None, _) => true,
+            (Some(allowed), Some(actual)) => allowed.contains(&actual),
+            (Some(_), None) => false,
+        }
+    }
+
+    ///
+    /// Parses a single value, a `{a,b}` set, or a `*` wildcard of the allowed characters.
+    ///
+    fn parse_set(
+        chars: &mut Peekable<Chars<'_>>,
+        allowed: &[char],
+        string: &str,
+    ) -> anyhow::Result<Vec<char>> {
+        match chars.next() {
+            Some('*') => Ok(allowed.to_vec()),
+            Some('{') => {
+                let mut set = Vec::with_capacity(allowed.len());
+                loop {
+                    match chars.next() {
+                        Some('}') if !set.is_empty() => return Ok(set),
+                        Some(',') if !set.is_empty() => continue,
+                        Some(character) if allowed.contains(&character) => set.push(character),
+                        _ => anyhow::bail!(
+                            "Mode specification `{string}` has an invalid set: expected any of {allowed:?}"
+                        ),
+                    }
+                }
+            }
+            Some(character) if allowed.contains(&character) => Ok(vec![character]),
+            _ => anyhow::bail!(
+                "Mode specification `{string}` has an invalid value: expected any of {allowed:?}"
+            ),
+        }
+    }
+}
+
+impl TryFrom<String> for Specification {
+    type Error = anyhow::Error;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl FromStr for Specification {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let trimmed = string.trim();
+        let (is_negated, trimmed) = match trimmed.strip_prefix('!') {
+            Some(trimmed) => (true, trimmed),
+            None => (false, trimmed),
+        };
+        let (code, versions) = match trimmed.split_once(' ') {
+            Some((code, versions)) => (code, Some(versions.trim())),
+            None => (trimmed, None),
+        };
+
+        let mut specification = Self {
+            is_negated,
+            is_optimized: false,
+            pipelines: None,
+            optimize: None,
+            levels_middle_end: None,
+            levels_back_end: None,
+            versions: None,
+        };
+
+        if code != "*" {
+            let mut chars = code.chars().peekable();
+            specification.is_optimized = chars.next_if_eq(&'^').is_some();
+            if matches!(chars.peek(), Some(character) if *character == '{' || Descriptor::PIPELINES.contains(character))
+            {
+                specification.pipelines =
+                    Some(Self::parse_set(&mut chars, &Descriptor::PIPELINES, string)?);
+            }
+            specification.optimize = match chars.next_if(|character| "+-*".contains(*character)) {
+                Some('+') => Some(true),
+                Some('-') => Some(false),
+                _ => None,
+            };
+            if chars.next_if_eq(&'M').is_some() {
+                specification.levels_middle_end = Some(if chars.next_if_eq(&'^').is_some() {
+                    vec!['3', 'z']
+                } else {
+                    Self::parse_set(&mut chars, &Descriptor::LEVELS_MIDDLE_END, string)?
+                });
+            }
+            if chars.next_if_eq(&'B').is_some() {
+                specification.levels_back_end = Some(if chars.next_if_eq(&'^').is_some() {
+                    vec!['3']
+                } else {
+                    Self::parse_set(&mut chars, &Descriptor::LEVELS_BACK_END, string)?
+                });
+            }
+            if let Some(character) = chars.next() {
+                anyhow::bail!(
+                    "Mode specification `{string}` has an unexpected character `{character}`"
+                );
+            }
+        }
+
+        specification.versions = match versions {
+            Some(versions)
+                if versions.starts_with(|character: char| character.is_ascii_digit()) =>
+            {
+                Some(semver::VersionReq::parse(format!("={versions}").as_str()))
+            }
+            Some(versions) => Some(semver::VersionReq::parse(versions)),
+            None => None,
+        }
+        .transpose()
+        .map_err(|error| anyhow::anyhow!("Mode specification `{string}` versions: {error}"))?;
+
+        Ok(specification)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Specification;
+    use crate::compilers::mode::descriptor::Descriptor;
+
+    fn check(specifications: &[&str], mode: &str) -> bool {
+        let specifications = specifications
+            .iter()
+            .map(|specification| Specification::from_str(specification).expect("Always valid"))
+            .collect::<Vec<Specification>>();
+        let descriptor = Descriptor::from_str(mode).expect("Always valid");
+        Specification::check_all(specifications.as_slice(), &descriptor)
+    }
+
+    #[test]
+    fn wildcard() {
+        assert!(check(&["*"], "Y+M3B3 0.8.24"));
+        assert!(check(&["*"], "M0B0"));
+        assert!(check(&["*"], ""));
+    }
+
+    #[test]
+    fn exact() {
+        assert!(check(&["Y+M3B3 0.8.24"], "Y+M3B3 0.8.24"));
+        assert!(!check(&["Y+M3B3 0.8.24"], "Y+M3B3 0.8.25"));
+        assert!(!check(&["Y+M3B3 0.8.24"], "Y-M3B3 0.8.24"));
+        assert!(!check(&["Y+M3B3 0.8.24"], "E+M3B3 0.8.24"));
+    }
+
+    #[test]
+    fn omitted_components() {
+        assert!(check(&["Y"], "Y-M0B0 0.8.24"));
+        assert!(check(&["+"], "V+MzB3 0.3.10"));
+        assert!(check(&["M3"], "M3B0"));
+        assert!(!check(&["Y"], "M3B3"));
+    }
+
+    #[test]
+    fn sets() {
+        assert!(check(&["{Y,E}+"], "E+M3B3 0.8.24"));
+        assert!(!check(&["{Y,E}+"], "y+M3B3 0.8.24"));
+        assert!(check(&["M{0,3}B*"], "Y+M0B2 0.8.24"));
+        assert!(!check(&["M{0,3}"], "Y+MzB3 0.8.24"));
+        assert!(check(&["*M*B*"], "V-M1B1 0.3.10"));
+    }
+
+    #[test]
+    fn optimized_levels() {
+        assert!(check(&["M^"], "Y+MzB3 0.8.24"));
+        assert!(check(&["M^"], "Y+M3B0 0.8.24"));
+        assert!(!check(&["M^"], "Y+MsB3 0.8.24"));
+        assert!(check(&["B^"], "Y+M0B3 0.8.24"));
+        assert!(!check(&["B^"], "Y+M3B2 0.8.24"));
+        assert!(check(&["M^B^"], "M3B3"));
+    }
+
+    #[test]
+    fn optimizer_required() {
+        assert!(check(&["^"], "Y+M0B0 0.8.24"));
+        assert!(check(&["^"], "M3B3"));
+        assert!(check(&["^"], "MzB0"));
+        assert!(!check(&["^"], "Y-M3B3 0.8.24"));
+        assert!(!check(&["^"], "M1B3"));
+    }
+
+    #[test]
+    fn negation() {
+        assert!(!check(&["!M0"], "Y+M0B3 0.8.24"));
+        assert!(check(&["!M0"], "Y+M3B3 0.8.24"));
+        assert!(check(&["Y+", "!M0"], "Y+M3B3 0.8.24"));
+        assert!(!check(&["Y+", "!M0"], "Y+M0B3 0.8.24"));
+        assert!(!check(&["Y+", "!M0"], "E+M3B3 0.8.24"));
+    }
+
+    #[test]
+    fn versions() {
+        assert!(check(&["Y >=0.8.0, <0.8.20"], "Y+M3B3 0.8.19"));
+        assert!(!check(&["Y >=0.8.0, <0.8.20"], "Y+M3B3 0.8.20"));
+        assert!(check(&["* ^0.4.0"], "E-M0B0 0.4.26"));
+        assert!(!check(&["* 0.8.24"], "Y+M3B3 0.8.25"));
+        assert!(!check(&["* >=0.8.0"], "M3B3"));
+    }
+
+    #[test]
+    fn components() {
+        let specification =
+            Specification::from_str("!^{Y,y}-M{1,2}B* >=0.8.0").expect("Always valid");
+
+        assert!(specification.is_negated);
+        assert!(specification.is_optimized);
+        assert_eq!(specification.pipelines, Some(vec!['Y', 'y']));
+        assert_eq!(specification.optimize, Some(false));
+        assert_eq!(specification.levels_middle_end, Some(vec!['1', '2']));
+        assert_eq!(
+            specification.levels_back_end,
+            Some(vec!['0', '1', '2', '3'])
+        );
+        assert_eq!(
+            specification.versions,
+            Some(semver::VersionReq::parse(">=0.8.0").expect("Always valid"))
+        );
+    }
+
+    #[test]
+    fn error_invalid_pipeline() {
+        assert!(Specification::from_str("Q+").is_err());
+    }
+
+    #[test]
+    fn error_invalid_set() {
+        assert!(Specification::from_str("M{}").is_err());
+        assert!(Specification::from_str("M{0,4}").is_err());
+        assert!(Specification::from_str("{Y,E").is_err());
+    }
+
+    #[test]
+    fn error_unexpected_character() {
+        assert!(Specification::from_str("Y+M3B3x").is_err());
+    }
+
+    #[test]
+    fn error_invalid_versions() {
+        assert!(Specification::from_str("Y+ >=0.8.x.y").is_err());
+    }
+}
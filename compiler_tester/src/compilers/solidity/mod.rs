@@ -12,6 +12,7 @@ use std::path::Path;
 
 use itertools::Itertools;
 
+use crate::compilers::cache::persistent::PersistentCache;
 use crate::compilers::cache::Cache;
 use crate::compilers::mode::Mode;
 use crate::compilers::Compiler;
@@ -248,7 +249,21 @@ impl SolidityCompiler {
 
         if !self.cache.contains(&cache_key) {
             self.cache.evaluate(cache_key.clone(), || {
-                Self::standard_json_output(sources, libraries, mode)
+                let solc = if mode.is_system_contracts_mode {
+                    Self::system_contract_executable()
+                } else {
+                    Self::executable(&mode.solc_version)
+                }?;
+                PersistentCache::load_or_evaluate(
+                    "solc",
+                    Path::new(solc.executable.as_str()),
+                    &[
+                        serde_json::to_vec(&cache_key)?.as_slice(),
+                        serde_json::to_vec(sources)?.as_slice(),
+                        serde_json::to_vec(libraries)?.as_slice(),
+                    ],
+                    || Self::standard_json_output(sources, libraries, mode),
+                )
             });
         }
 
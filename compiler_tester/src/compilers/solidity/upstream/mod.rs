@@ -9,6 +9,7 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::compilers::cache::persistent::PersistentCache;
 use crate::compilers::cache::Cache;
 use crate::compilers::mode::Mode;
 use crate::compilers::solidity::cache_key::CacheKey;
@@ -197,7 +198,17 @@ impl SolidityCompiler {
 
         if !self.cache.contains(&cache_key) {
             self.cache.evaluate(cache_key.clone(), || {
-                Self::standard_json_output(sources, libraries, mode)
+                let solc = Self::executable(&mode.solc_version)?;
+                PersistentCache::load_or_evaluate(
+                    "solc-upstream",
+                    Path::new(solc.executable.as_str()),
+                    &[
+                        serde_json::to_vec(&cache_key)?.as_slice(),
+                        serde_json::to_vec(sources)?.as_slice(),
+                        serde_json::to_vec(libraries)?.as_slice(),
+                    ],
+                    || Self::standard_json_output(sources, libraries, mode),
+                )
             });
         }
 
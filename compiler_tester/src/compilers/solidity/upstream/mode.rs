@@ -5,6 +5,7 @@
 use itertools::Itertools;
 
 use crate::compilers::mode::Mode as ModeWrapper;
+use crate::compilers::solidity::upstream::solc::Compiler as SolcUpstreamCompiler;
 
 ///
 /// The compiler tester Solidity mode.
@@ -107,6 +108,41 @@ impl Mode {
             }
         }
     }
+
+    ///
+    /// Returns the Ethereum test gas expectation matching the mode, if specified.
+    ///
+    pub fn expected_gas(
+        &self,
+        gas_options: &[solidity_adapter::GasOption],
+    ) -> Option<web3::types::U256> {
+        if !self.is_gas_comparable() {
+            return None;
+        }
+
+        let is_ir =
+            self.via_ir || matches!(self.solc_pipeline, era_compiler_solidity::SolcPipeline::Yul);
+        gas_options
+            .iter()
+            .find_map(|option| match (option, is_ir, self.solc_optimize) {
+                (solidity_adapter::GasOption::IrOptimized(gas), true, true) => Some(*gas),
+                (solidity_adapter::GasOption::Ir(gas), true, false) => Some(*gas),
+                (solidity_adapter::GasOption::LegacyOptimized(gas), false, true) => Some(*gas),
+                (solidity_adapter::GasOption::Legacy(gas), false, false) => Some(*gas),
+                _ => None,
+            })
+    }
+
+    ///
+    /// Whether the code is compiled for the `Shanghai` EVM version, which the native EVM executes.
+    ///
+    /// The gas used by other EVM versions differs due to the fork rules rather than the code,
+    /// so their gas expectations are not checked.
+    ///
+    pub fn is_gas_comparable(&self) -> bool {
+        self.solc_version >= SolcUpstreamCompiler::FIRST_SHANGHAI_VERSION
+            && self.solc_version < SolcUpstreamCompiler::FIRST_CANCUN_VERSION
+    }
 }
 
 impl std::fmt::Display for Mode {
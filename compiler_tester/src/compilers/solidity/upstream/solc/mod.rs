@@ -25,6 +25,9 @@ impl Compiler {
     /// The first version of `solc`, where `--via-ir` codegen mode is supported.
     pub const FIRST_VIA_IR_VERSION: semver::Version = semver::Version::new(0, 8, 13);
 
+    /// The first version of `solc`, where `Shanghai` is the default EVM version.
+    pub const FIRST_SHANGHAI_VERSION: semver::Version = semver::Version::new(0, 8, 20);
+
     /// The first version of `solc`, where `Cancun` EVM version is supported.
     pub const FIRST_CANCUN_VERSION: semver::Version = semver::Version::new(0, 8, 24);
 
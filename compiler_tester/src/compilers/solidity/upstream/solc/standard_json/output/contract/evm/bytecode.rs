@@ -3,11 +3,12 @@
 //!
 
 use serde::Deserialize;
+use serde::Serialize;
 
 ///
 /// The `solc --standard-json` output contract EVM bytecode.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Bytecode {
     /// The bytecode object.
@@ -7,13 +7,14 @@ pub mod bytecode;
 use std::collections::BTreeMap;
 
 use serde::Deserialize;
+use serde::Serialize;
 
 use self::bytecode::Bytecode;
 
 ///
 /// The `solc --standard-json` output contract EVM data.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct EVM {
     /// The contract bytecode.
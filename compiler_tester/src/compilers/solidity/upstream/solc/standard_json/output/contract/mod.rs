@@ -8,13 +8,14 @@ use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use serde::Deserialize;
+use serde::Serialize;
 
 use self::evm::EVM;
 
 ///
 /// The `solc --standard-json` output contract.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Contract {
     /// The contract ABI.
@@ -2,10 +2,14 @@
 //! The Vyper compiler cache key.
 //!
 
+use serde::Serialize;
+
 ///
 /// The Vyper compiler cache key.
 ///
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// It is also serialized into the persistent cache entry key.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct CacheKey {
     /// The test path.
     pub test_path: String,
@@ -13,11 +13,14 @@ use std::str::FromStr;
 
 use itertools::Itertools;
 
+use crate::compilers::cache::persistent::PersistentCache;
 use crate::compilers::cache::Cache;
 use crate::compilers::mode::Mode;
 use crate::compilers::Compiler;
 use crate::vm::eravm::input::build::Build as EraVMBuild;
 use crate::vm::eravm::input::Input as EraVMInput;
+use crate::vm::evm::input::build::Build as EVMBuild;
+use crate::vm::evm::input::Input as EVMInput;
 
 use self::cache_key::CacheKey;
 use self::mode::Mode as VyperMode;
@@ -153,8 +156,18 @@ impl VyperCompiler {
         let cache_key = CacheKey::new(test_path, mode.vyper_version.clone(), mode.vyper_optimize);
 
         if !self.cache.contains(&cache_key) {
-            self.cache
-                .evaluate(cache_key.clone(), || Self::get_project(sources, mode));
+            self.cache.evaluate(cache_key.clone(), || {
+                let vyper = Self::executable(&mode.vyper_version)?;
+                PersistentCache::load_or_evaluate(
+                    "vyper",
+                    Path::new(vyper.executable.as_str()),
+                    &[
+                        serde_json::to_vec(&cache_key)?.as_slice(),
+                        serde_json::to_vec(&sources)?.as_slice(),
+                    ],
+                    || Self::get_project(sources, mode),
+                )
+            });
         }
 
         self.cache.get_cloned(&cache_key)
@@ -286,13 +299,48 @@ impl Compiler for VyperCompiler {
 
     fn compile_for_evm(
         &self,
-        _test_path: String,
-        _sources: Vec<(String, String)>,
+        test_path: String,
+        sources: Vec<(String, String)>,
         _libraries: BTreeMap<String, BTreeMap<String, String>>,
-        _mode: &Mode,
-        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
-    ) -> anyhow::Result<crate::vm::evm::input::Input> {
-        todo!()
+        mode: &Mode,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<EVMInput> {
+        let mode = VyperMode::unwrap(mode);
+
+        if let Some(ref debug_config) = debug_config {
+            Self::dump_lll(&sources, debug_config, mode)?;
+        }
+
+        let last_contract = sources
+            .last()
+            .ok_or_else(|| anyhow::anyhow!("The Vyper sources are empty"))?
+            .0
+            .clone();
+
+        let project = self
+            .get_project_cached(test_path, sources, mode)
+            .map_err(|error| anyhow::anyhow!("Failed to get vyper project: {}", error))?;
+
+        let method_identifiers = Self::get_method_identifiers(&project)
+            .map_err(|error| anyhow::anyhow!("Failed to get method identifiers: {}", error))?;
+
+        let build =
+            project.compile_to_evm(mode.llvm_optimizer_settings.to_owned(), false, debug_config)?;
+
+        let builds: HashMap<String, EVMBuild> = build
+            .contracts
+            .into_iter()
+            .map(|(path, contract)| {
+                let build = EVMBuild::new(contract.deploy_build, contract.runtime_build);
+                (path, build)
+            })
+            .collect::<HashMap<String, EVMBuild>>();
+
+        Ok(EVMInput::new(
+            builds,
+            Some(method_identifiers),
+            last_contract,
+        ))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
@@ -0,0 +1,41 @@
+//!
+//! The test corpus collection type.
+//!
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+///
+/// The test corpus collection type.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Collection {
+    /// The Matter Labs tests with metadata, one test per file.
+    MatterLabsDirectory,
+    /// The Ethereum tests described by an `index.yaml` file.
+    EthereumDirectory,
+}
+
+impl FromStr for Collection {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "MatterLabsDirectory" => Ok(Self::MatterLabsDirectory),
+            "EthereumDirectory" => Ok(Self::EthereumDirectory),
+            string => anyhow::bail!(
+                "Unknown collection `{string}`. Supported collections: MatterLabsDirectory, EthereumDirectory"
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Collection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MatterLabsDirectory => write!(f, "MatterLabsDirectory"),
+            Self::EthereumDirectory => write!(f, "EthereumDirectory"),
+        }
+    }
+}
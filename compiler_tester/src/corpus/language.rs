@@ -0,0 +1,68 @@
+//!
+//! The test corpus language.
+//!
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+///
+/// The test corpus language, which selects the compiler.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Language {
+    /// The Solidity language.
+    Solidity,
+    /// The Vyper language.
+    Vyper,
+    /// The Yul language.
+    Yul,
+    /// The LLVM IR.
+    LLVM,
+    /// The EraVM assembly.
+    EraVM,
+}
+
+impl Language {
+    ///
+    /// Returns the default source file extension.
+    ///
+    pub fn default_extension(&self) -> &'static str {
+        match self {
+            Self::Solidity => era_compiler_common::EXTENSION_SOLIDITY,
+            Self::Vyper => era_compiler_common::EXTENSION_VYPER,
+            Self::Yul => era_compiler_common::EXTENSION_YUL,
+            Self::LLVM => era_compiler_common::EXTENSION_LLVM_SOURCE,
+            Self::EraVM => era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "Solidity" => Ok(Self::Solidity),
+            "Vyper" => Ok(Self::Vyper),
+            "Yul" => Ok(Self::Yul),
+            "LLVM" => Ok(Self::LLVM),
+            "EraVM" => Ok(Self::EraVM),
+            string => anyhow::bail!(
+                "Unknown language `{string}`. Supported languages: Solidity, Vyper, Yul, LLVM, EraVM"
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solidity => write!(f, "Solidity"),
+            Self::Vyper => write!(f, "Vyper"),
+            Self::Yul => write!(f, "Yul"),
+            Self::LLVM => write!(f, "LLVM"),
+            Self::EraVM => write!(f, "EraVM"),
+        }
+    }
+}
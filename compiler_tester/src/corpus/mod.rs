@@ -0,0 +1,92 @@
+//!
+//! The additional test corpus.
+//!
+
+pub mod collection;
+pub mod language;
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use self::collection::Collection;
+use self::language::Language;
+
+///
+/// The additional test corpus, declared in a config file or on the command line.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Corpus {
+    /// The tests directory path.
+    pub path: PathBuf,
+    /// The collection type.
+    pub collection: Collection,
+    /// The language, which selects the compiler.
+    pub language: Language,
+    /// The test file extension. Defaults to the language extension if unset.
+    pub extension: Option<String>,
+}
+
+impl Corpus {
+    ///
+    /// Reads the corpus list file.
+    ///
+    /// The file is a YAML or JSON list of corpora.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Vec<Self>> {
+        let file = std::fs::File::open(path)
+            .map_err(|error| anyhow::anyhow!("Corpora file {:?} opening: {}", path, error))?;
+        let corpora = serde_yaml::from_reader(std::io::BufReader::new(file))
+            .map_err(|error| anyhow::anyhow!("Corpora file {:?} parsing: {}", path, error))?;
+        Ok(corpora)
+    }
+
+    ///
+    /// Returns the test file extension.
+    ///
+    pub fn extension(&self) -> &str {
+        match self.extension.as_deref() {
+            Some(extension) => extension,
+            None => self.language.default_extension(),
+        }
+    }
+}
+
+impl FromStr for Corpus {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses the command line form, e.g.
+    /// `path=../tests/solidity,collection=MatterLabsDirectory,language=Solidity,extension=sol`.
+    ///
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut path = None;
+        let mut collection = None;
+        let mut language = None;
+        let mut extension = None;
+
+        for pair in string.split(',') {
+            let (key, value) = pair.split_once('=').ok_or_else(|| {
+                anyhow::anyhow!("Corpus `{string}` has an invalid key-value pair `{pair}`")
+            })?;
+            match key.trim() {
+                "path" => path = Some(PathBuf::from(value.trim())),
+                "collection" => collection = Some(Collection::from_str(value.trim())?),
+                "language" => language = Some(Language::from_str(value.trim())?),
+                "extension" => extension = Some(value.trim().to_owned()),
+                key => anyhow::bail!("Corpus `{string}` has an unknown key `{key}`"),
+            }
+        }
+
+        Ok(Self {
+            path: path.ok_or_else(|| anyhow::anyhow!("Corpus `{string}` has no path"))?,
+            collection: collection
+                .ok_or_else(|| anyhow::anyhow!("Corpus `{string}` has no collection"))?,
+            language: language
+                .ok_or_else(|| anyhow::anyhow!("Corpus `{string}` has no language"))?,
+            extension,
+        })
+    }
+}
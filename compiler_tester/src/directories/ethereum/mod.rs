@@ -42,7 +42,7 @@ impl Collection for EthereumDirectory {
 
     fn read_all(
         directory_path: &Path,
-        _extension: &'static str,
+        _extension: &str,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
     ) -> anyhow::Result<Vec<Self::Test>> {
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::compilers::mode::specification::Specification;
 use crate::compilers::mode::Mode;
 use crate::compilers::Compiler;
 use crate::directories::Buildable;
@@ -28,6 +29,8 @@ pub struct EthereumTest {
     pub identifier: String,
     /// The index test entity.
     pub index_entity: solidity_adapter::EnabledTest,
+    /// The mode filter parsed from the index test entity.
+    pub modes: Option<Vec<Specification>>,
     /// The test data.
     pub test: solidity_adapter::Test,
 }
@@ -47,10 +50,32 @@ impl EthereumTest {
             return None;
         }
 
+        if !filters.check_rerun_test(identifier.as_str()) {
+            return None;
+        }
+
         if !filters.check_group(&index_entity.group) {
             return None;
         }
 
+        let modes = match index_entity
+            .modes
+            .as_ref()
+            .map(|modes| {
+                modes
+                    .iter()
+                    .map(|mode| mode.parse())
+                    .collect::<anyhow::Result<Vec<Specification>>>()
+            })
+            .transpose()
+        {
+            Ok(modes) => modes,
+            Err(error) => {
+                Summary::invalid(summary, None, identifier, error);
+                return None;
+            }
+        };
+
         let test = match solidity_adapter::Test::try_from(index_entity.path.as_path()) {
             Ok(test) => test,
             Err(error) => {
@@ -62,6 +87,7 @@ impl EthereumTest {
         Some(Self {
             identifier,
             index_entity,
+            modes,
             test,
         })
     }
@@ -73,8 +99,11 @@ impl EthereumTest {
         if !filters.check_mode(mode) {
             return None;
         }
-        if let Some(filters) = self.index_entity.modes.as_ref() {
-            if !mode.check_extended_filters(filters.as_slice()) {
+        if !filters.check_rerun_mode(self.identifier.as_str(), mode) {
+            return None;
+        }
+        if let Some(filters) = self.modes.as_ref() {
+            if !mode.check_filters(filters.as_slice()) {
                 return None;
             }
         }
@@ -188,6 +217,17 @@ impl EthereumTest {
 }
 
 impl Buildable for EthereumTest {
+    fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    fn list(&self, mode: &Mode, _target: Target, filters: &Filters) -> Vec<Option<String>> {
+        match self.check_filters(filters, mode) {
+            Some(()) => vec![None],
+            None => vec![],
+        }
+    }
+
     fn build_for_eravm(
         &self,
         mode: Mode,
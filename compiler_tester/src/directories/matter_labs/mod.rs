@@ -25,7 +25,7 @@ impl Collection for MatterLabsDirectory {
 
     fn read_all(
         directory_path: &Path,
-        extension: &'static str,
+        extension: &str,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
     ) -> anyhow::Result<Vec<Self::Test>> {
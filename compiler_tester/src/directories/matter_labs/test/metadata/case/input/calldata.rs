@@ -4,6 +4,8 @@
 
 use serde::Deserialize;
 
+use super::typed_values::TypedValues;
+
 ///
 /// The Matter Labs compiler test metadata case input calldata.
 ///
@@ -14,6 +16,8 @@ pub enum Calldata {
     Value(String),
     /// The list of values.
     List(Vec<String>),
+    /// The ABI-typed values.
+    Typed(TypedValues),
 }
 
 impl Default for Calldata {
@@ -4,10 +4,13 @@
 
 pub mod variant;
 
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::compilers::mode::Mode;
 
+use self::variant::extended::return_data::ReturnData;
 use self::variant::extended::Extended;
 use self::variant::Variant;
 
@@ -36,9 +39,14 @@ impl Expected {
     ///
     pub fn successful_evm_interpreter_benchmark(exception: bool) -> Self {
         Self::Single(Variant::Extended(Extended {
-            return_data: vec![],
+            return_data: ReturnData::List(vec![]),
             events: vec![],
             exception,
+            exception_kind: None,
+            revert: None,
+            panic: None,
+            custom_error: None,
+            storage: HashMap::new(),
             compiler_version: None,
         }))
     }
@@ -66,7 +74,7 @@ impl Expected {
             .ok_or_else(|| anyhow::anyhow!("Version is not covered"))?;
         Ok(match variant {
             Variant::Simple(_) => false,
-            Variant::Extended(inner) => inner.exception,
+            Variant::Extended(inner) => inner.is_exception(),
         })
     }
 }
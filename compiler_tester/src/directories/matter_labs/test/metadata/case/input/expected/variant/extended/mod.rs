@@ -3,10 +3,17 @@
 //!
 
 pub mod event;
+pub mod return_data;
+
+use std::collections::HashMap;
 
 use serde::Deserialize;
 
+use crate::directories::matter_labs::test::metadata::case::input::storage::Storage;
+use crate::test::case::input::output::exception_kind::ExceptionKind;
+
 use self::event::Event;
+use self::return_data::ReturnData;
 
 ///
 /// The Matter Labs compiler test metadata extended expected data.
@@ -14,13 +21,39 @@ use self::event::Event;
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct Extended {
     /// The return data values.
-    pub return_data: Vec<String>,
+    pub return_data: ReturnData,
     /// The emitted events.
     #[serde(default)]
     pub events: Vec<Event>,
     /// Whether an exception is expected,
     #[serde(default)]
     pub exception: bool,
+    /// The expected exception kind, which implies an exception.
+    /// If omitted, any kind of exception is accepted.
+    pub exception_kind: Option<ExceptionKind>,
+    /// The expected `Error(string)` revert message, which implies an exception.
+    pub revert: Option<String>,
+    /// The expected `Panic(uint256)` code, e.g. `0x11`, which implies an exception.
+    pub panic: Option<serde_json::Value>,
+    /// The expected custom error signature, e.g. `Err(uint256)`, which implies an exception.
+    /// The error arguments are taken from the return data.
+    pub custom_error: Option<String>,
+    /// The expected contracts storage after the input runs, where `*` matches any value.
+    #[serde(default)]
+    pub storage: HashMap<String, Storage>,
     /// The compiler version filter.
     pub compiler_version: Option<semver::VersionReq>,
 }
+
+impl Extended {
+    ///
+    /// Whether an exception is expected, either explicitly or by a revert expectation.
+    ///
+    pub fn is_exception(&self) -> bool {
+        self.exception
+            || self.exception_kind.is_some()
+            || self.revert.is_some()
+            || self.panic.is_some()
+            || self.custom_error.is_some()
+    }
+}
@@ -0,0 +1,25 @@
+//!
+//! The Matter Labs compiler test metadata extended expected return data.
+//!
+
+use serde::Deserialize;
+
+use crate::directories::matter_labs::test::metadata::case::input::typed_values::TypedValues;
+
+///
+/// The Matter Labs compiler test metadata extended expected return data.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ReturnData {
+    /// The list of 32-byte words.
+    List(Vec<String>),
+    /// The ABI-typed values.
+    Typed(TypedValues),
+}
+
+impl Default for ReturnData {
+    fn default() -> Self {
+        Self::List(vec![])
+    }
+}
@@ -5,11 +5,13 @@
 pub mod calldata;
 pub mod expected;
 pub mod storage;
+pub mod typed_values;
 
 use std::collections::HashMap;
 
 use crate::directories::matter_labs::test::default_caller_address;
 use crate::directories::matter_labs::test::simple_tests_instance;
+use crate::vm::chain_context::ChainContext;
 
 use self::calldata::Calldata;
 use self::expected::Expected;
@@ -41,6 +43,9 @@ pub struct Input {
     pub storage: HashMap<String, Storage>,
     /// The expected return data.
     pub expected: Option<Expected>,
+    /// Overrides the case chain context values.
+    #[serde(default)]
+    pub chain_context: ChainContext,
 }
 
 impl Input {
@@ -57,6 +62,7 @@ impl Input {
             expected: Some(Expected::successful_deployer_expected(instance)),
             method: "#deployer".to_string(),
             storage: HashMap::new(),
+            chain_context: ChainContext::default(),
         }
     }
 }
@@ -21,3 +21,19 @@ impl Default for Storage {
         Self::Map(HashMap::new())
     }
 }
+
+impl Storage {
+    ///
+    /// Returns the key-value pairs, where the list keys are the indexes.
+    ///
+    pub fn into_entries(self) -> HashMap<String, String> {
+        match self {
+            Self::List(list) => list
+                .into_iter()
+                .enumerate()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+            Self::Map(map) => map,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//!
+//! The Matter Labs compiler test metadata ABI-typed values.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Matter Labs compiler test metadata ABI-typed values.
+///
+/// The values are ABI-encoded according to the types, e.g. `uint8`, `string`, or `(uint256,address)[]`.
+/// Tuples and arrays are written as JSON arrays, and addresses may refer to instances as `<instance>.address`.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct TypedValues {
+    /// The Solidity ABI types.
+    pub types: Vec<String>,
+    /// The values, one per type.
+    pub values: Vec<serde_json::Value>,
+}
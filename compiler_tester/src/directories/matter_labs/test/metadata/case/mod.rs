@@ -9,10 +9,12 @@ use std::str::FromStr;
 
 use serde::Deserialize;
 
+use crate::compilers::mode::specification::Specification;
 use crate::compilers::mode::Mode;
 use crate::target::Target;
 use crate::test::instance::Instance;
 use crate::vm::address_iterator::AddressIterator;
+use crate::vm::chain_context::ChainContext;
 use crate::vm::eravm::address_iterator::EraVMAddressIterator;
 use crate::vm::evm::address_iterator::EVMAddressIterator;
 
@@ -29,7 +31,7 @@ pub struct Case {
     /// The case name.
     pub name: String,
     /// The mode filter.
-    pub modes: Option<Vec<String>>,
+    pub modes: Option<Vec<Specification>>,
     /// The case inputs.
     pub inputs: Vec<Input>,
     /// The expected return data.
@@ -37,8 +39,15 @@ pub struct Case {
     /// If the test case must be ignored.
     #[serde(default)]
     pub ignore: bool,
-    /// Overrides the default number of cycles.
+    /// Overrides the default EraVM cycles limit per transaction.
     pub cycles: Option<usize>,
+    /// Overrides the default EraVM ergs limit per transaction.
+    pub ergs: Option<u64>,
+    /// Overrides the default EVM transaction gas limit.
+    pub gas: Option<u64>,
+    /// Overrides the default chain context values.
+    #[serde(default)]
+    pub chain_context: ChainContext,
 }
 
 impl Case {
@@ -8,6 +8,7 @@ pub mod evm_contract;
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use crate::compilers::mode::specification::Specification;
 use crate::target::Target;
 
 use self::case::Case;
@@ -21,7 +22,7 @@ pub struct Metadata {
     /// The test cases.
     pub cases: Vec<Case>,
     /// The mode filter.
-    pub modes: Option<Vec<String>>,
+    pub modes: Option<Vec<Specification>>,
     /// The test contracts.
     /// The format is `instance -> path`.
     #[serde(default)]
@@ -22,6 +22,7 @@ use crate::test::case::Case;
 use crate::test::instance::Instance;
 use crate::test::Test;
 use crate::vm::address_iterator::AddressIterator;
+use crate::vm::chain_context::ChainContext;
 use crate::vm::eravm::address_iterator::EraVMAddressIterator;
 use crate::vm::evm::address_iterator::EVMAddressIterator;
 
@@ -80,6 +81,10 @@ impl MatterLabsTest {
             return None;
         }
 
+        if !filters.check_rerun_test(identifier.as_str()) {
+            return None;
+        }
+
         let main_file_string = match std::fs::read_to_string(path.as_path()) {
             Ok(data) => data,
             Err(error) => {
@@ -180,8 +185,11 @@ impl MatterLabsTest {
         if !filters.check_mode(mode) {
             return None;
         }
+        if !filters.check_rerun_mode(self.identifier.as_str(), mode) {
+            return None;
+        }
         if let Some(filters) = self.metadata.modes.as_ref() {
-            if !mode.check_extended_filters(filters.as_slice()) {
+            if !mode.check_filters(filters.as_slice()) {
                 return None;
             }
         }
@@ -296,6 +304,35 @@ impl MatterLabsTest {
         Ok(instances)
     }
 
+    ///
+    /// Returns the cases selected by the filters in the mode.
+    ///
+    /// Is shared by the listing and building, so both schedule the same cases.
+    /// The EVM interpreter benchmark cases are only run on EraVM.
+    ///
+    fn selected_cases(
+        &self,
+        mode: &Mode,
+        target: Target,
+        filters: &Filters,
+    ) -> Vec<MatterLabsCase> {
+        let mut cases = self.metadata.cases.to_owned();
+        if let Target::EraVM = target {
+            cases.extend(self.evm_interpreter_benchmark_cases());
+        }
+
+        cases
+            .into_iter()
+            .filter(|case| match case.modes.as_ref() {
+                Some(filters) => mode.check_filters(filters.as_slice()),
+                None => true,
+            })
+            .filter(|case| {
+                filters.check_rerun_case(self.identifier.as_str(), case.name.as_str(), mode)
+            })
+            .collect()
+    }
+
     ///
     /// Returns cases needed for running benchmarks on the EVM interpreter.
     ///
@@ -345,6 +382,7 @@ impl MatterLabsTest {
                                 false,
                             ),
                         ),
+                        chain_context: ChainContext::default(),
                     },
                     MatterLabsCaseInput {
                         comment: None,
@@ -362,6 +400,7 @@ impl MatterLabsTest {
                                 exception,
                             ),
                         ),
+                        chain_context: ChainContext::default(),
                     },
                 ],
                 expected: MatterLabsCaseInputExpected::successful_evm_interpreter_benchmark(
@@ -369,6 +408,9 @@ impl MatterLabsTest {
                 ),
                 ignore: false,
                 cycles: None,
+                ergs: None,
+                gas: None,
+                chain_context: ChainContext::default(),
             })
         }
         metadata_cases
@@ -376,6 +418,26 @@ impl MatterLabsTest {
 }
 
 impl Buildable for MatterLabsTest {
+    fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    fn list(&self, mode: &Mode, target: Target, filters: &Filters) -> Vec<Option<String>> {
+        let mut mode = mode.to_owned();
+        if let Target::EraVM = target {
+            mode.set_system_mode(self.metadata.system_mode);
+        }
+
+        if self.check_filters(filters, &mode).is_none() {
+            return vec![];
+        }
+
+        self.selected_cases(&mode, target, filters)
+            .into_iter()
+            .map(|case| Some(case.name))
+            .collect()
+    }
+
     fn build_for_eravm(
         &self,
         mut mode: Mode,
@@ -436,17 +498,10 @@ impl Buildable for MatterLabsTest {
         };
         instances.extend(evm_instances);
 
-        let mut metadata_cases = self.metadata.cases.to_owned();
-        metadata_cases.extend(self.evm_interpreter_benchmark_cases());
+        let metadata_cases = self.selected_cases(&mode, target, filters);
 
         let mut cases = Vec::with_capacity(metadata_cases.len());
         for case in metadata_cases.into_iter() {
-            if let Some(filters) = case.modes.as_ref() {
-                if !mode.check_extended_filters(filters.as_slice()) {
-                    continue;
-                }
-            }
-
             let case = match case.normalize(&contracts, &instances, target) {
                 Ok(case) => case,
                 Err(error) => {
@@ -557,15 +612,11 @@ impl Buildable for MatterLabsTest {
             }
         };
 
-        let mut cases = Vec::with_capacity(self.metadata.cases.len());
-        for case in self.metadata.cases.iter() {
-            if let Some(filters) = case.modes.as_ref() {
-                if !mode.check_extended_filters(filters.as_slice()) {
-                    continue;
-                }
-            }
+        let metadata_cases = self.selected_cases(&mode, target, filters);
 
-            let case = match case.to_owned().normalize(&contracts, &instances, target) {
+        let mut cases = Vec::with_capacity(metadata_cases.len());
+        for case in metadata_cases.into_iter() {
+            let case = match case.normalize(&contracts, &instances, target) {
                 Ok(case) => case,
                 Err(error) => {
                     Summary::invalid(summary, Some(mode), self.identifier.to_owned(), error);
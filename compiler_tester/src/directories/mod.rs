@@ -30,7 +30,7 @@ pub trait Collection {
     ///
     fn read_all(
         directory_path: &Path,
-        extension: &'static str,
+        extension: &str,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
     ) -> anyhow::Result<Vec<Self::Test>>;
@@ -40,6 +40,18 @@ pub trait Collection {
 /// The buildable compiler test trait.
 ///
 pub trait Buildable: Send + Sync + 'static {
+    ///
+    /// Returns the test identifier.
+    ///
+    fn identifier(&self) -> &str;
+
+    ///
+    /// Returns the cases scheduled in the mode without building the test.
+    ///
+    /// The anonymous case of a test is returned without a name.
+    ///
+    fn list(&self, mode: &Mode, target: Target, filters: &Filters) -> Vec<Option<String>>;
+
     ///
     /// Builds the test for EraVM.
     ///
@@ -2,10 +2,15 @@
 //! The compiler tester filters.
 //!
 
+pub mod rerun;
+
 use std::collections::HashSet;
 
+use crate::compilers::mode::specification::Specification as ModeSpecification;
 use crate::compilers::mode::Mode;
 
+use self::rerun::Rerun;
+
 ///
 /// The compiler tester filters.
 ///
@@ -14,9 +19,11 @@ pub struct Filters {
     /// The path filters.
     path_filters: HashSet<String>,
     /// The mode filters.
-    mode_filters: HashSet<String>,
+    mode_filters: Vec<ModeSpecification>,
     /// The group filters.
     group_filters: HashSet<String>,
+    /// The exact (test, case, mode) triples to rerun from a previous report.
+    rerun: Option<Rerun>,
 }
 
 impl Filters {
@@ -25,16 +32,25 @@ impl Filters {
     ///
     pub fn new(
         path_filters: Vec<String>,
-        mode_filters: Vec<String>,
+        mode_filters: Vec<ModeSpecification>,
         group_filters: Vec<String>,
     ) -> Self {
         Self {
             path_filters: path_filters.into_iter().collect(),
-            mode_filters: mode_filters.into_iter().collect(),
+            mode_filters,
             group_filters: group_filters.into_iter().collect(),
+            rerun: None,
         }
     }
 
+    ///
+    /// Restricts the filters to the triples to rerun from a previous report.
+    ///
+    pub fn with_rerun(mut self, rerun: Rerun) -> Self {
+        self.rerun = Some(rerun);
+        self
+    }
+
     ///
     /// Check if the test path is compatible with the filters.
     ///
@@ -59,7 +75,7 @@ impl Filters {
     /// Check if the mode is compatible with the filters.
     ///
     pub fn check_mode(&self, mode: &Mode) -> bool {
-        mode.check_filters(&self.mode_filters)
+        mode.check_filters(self.mode_filters.as_slice())
     }
 
     ///
@@ -76,4 +92,34 @@ impl Filters {
             false
         }
     }
+
+    ///
+    /// Check if the test is scheduled for rerun in any mode.
+    ///
+    pub fn check_rerun_test(&self, identifier: &str) -> bool {
+        match self.rerun.as_ref() {
+            Some(rerun) => rerun.contains_test(identifier),
+            None => true,
+        }
+    }
+
+    ///
+    /// Check if the test is scheduled for rerun in the mode.
+    ///
+    pub fn check_rerun_mode(&self, identifier: &str, mode: &Mode) -> bool {
+        match self.rerun.as_ref() {
+            Some(rerun) => rerun.contains_mode(identifier, mode),
+            None => true,
+        }
+    }
+
+    ///
+    /// Check if the test case is scheduled for rerun in the mode.
+    ///
+    pub fn check_rerun_case(&self, identifier: &str, case: &str, mode: &Mode) -> bool {
+        match self.rerun.as_ref() {
+            Some(rerun) => rerun.contains_case(identifier, case, mode),
+            None => true,
+        }
+    }
 }
@@ -0,0 +1,240 @@
+//!
+//! The compiler tester rerun filter.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::compilers::mode::Mode;
+use crate::summary::report::record::Record;
+use crate::summary::report::record::RecordOutcome;
+
+///
+/// The compiler tester rerun filter.
+///
+/// Selects exactly the (test, case, mode) triples which have not passed in a previous run.
+///
+#[derive(Debug, Default)]
+pub struct Rerun {
+    /// The cases to rerun, keyed by the test identifier.
+    /// The mode is unset for elements reported without one, and matches any mode.
+    /// The case is unset for elements reported for the whole test, and matches any case.
+    tests: HashMap<String, HashSet<(Option<String>, Option<String>)>>,
+}
+
+impl Rerun {
+    ///
+    /// Reads the failed and invalid elements from a JSON lines report.
+    ///
+    /// The known failures from the baseline are skipped.
+    ///
+    pub fn try_from_report(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Report file {:?} reading: {}", path, error))?;
+        Self::try_from_json_lines(contents.as_str())
+            .map_err(|error| anyhow::anyhow!("Report file {:?} {}", path, error))
+    }
+
+    ///
+    /// Reads the failed and invalid elements from the JSON lines report contents.
+    ///
+    fn try_from_json_lines(contents: &str) -> anyhow::Result<Self> {
+        let mut rerun = Self::default();
+        for (index, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: Record = serde_json::from_str(line).map_err(|error| {
+                anyhow::anyhow!(
+                    "line {} parsing: {}. Only the `json` report format is supported",
+                    index + 1,
+                    error
+                )
+            })?;
+            if record.is_expected_failure {
+                continue;
+            }
+            match record.outcome {
+                RecordOutcome::Passed | RecordOutcome::Ignored => continue,
+                RecordOutcome::Failed
+                | RecordOutcome::GasMismatch
+                | RecordOutcome::Diverged
+                | RecordOutcome::Invalid
+                | RecordOutcome::Crashed => {}
+            }
+
+            let (identifier, case) = Self::split_name(record.name.as_str());
+            rerun
+                .tests
+                .entry(identifier.to_owned())
+                .or_default()
+                .insert((case.map(|case| case.to_owned()), record.mode));
+        }
+        Ok(rerun)
+    }
+
+    ///
+    /// Whether the test must be rerun in any mode.
+    ///
+    pub fn contains_test(&self, identifier: &str) -> bool {
+        self.tests.contains_key(identifier)
+    }
+
+    ///
+    /// Whether the test must be rerun in the specified mode.
+    ///
+    pub fn contains_mode(&self, identifier: &str, mode: &Mode) -> bool {
+        let mode = mode.to_string();
+        match self.tests.get(identifier) {
+            Some(cases) => cases
+                .iter()
+                .any(|(_, case_mode)| Self::matches_mode(case_mode, mode.as_str())),
+            None => false,
+        }
+    }
+
+    ///
+    /// Whether the test case must be rerun in the specified mode.
+    ///
+    pub fn contains_case(&self, identifier: &str, case: &str, mode: &Mode) -> bool {
+        let mode = mode.to_string();
+        match self.tests.get(identifier) {
+            Some(cases) => cases.iter().any(|(case_name, case_mode)| {
+                (case_name.is_none() || case_name.as_deref() == Some(case))
+                    && Self::matches_mode(case_mode, mode.as_str())
+            }),
+            None => false,
+        }
+    }
+
+    ///
+    /// Whether the reported mode matches the mode, with an unset one matching any.
+    ///
+    fn matches_mode(reported: &Option<String>, mode: &str) -> bool {
+        reported.is_none() || reported.as_deref() == Some(mode)
+    }
+
+    ///
+    /// Splits the summary element name into the test identifier and the case name.
+    ///
+    /// The element names have the `<identifier>[::<case>][[<input>]]` format.
+    ///
+    pub fn split_name(name: &str) -> (&str, Option<&str>) {
+        let name = match name.find('[') {
+            Some(index) if name.ends_with(']') => &name[..index],
+            _ => name,
+        };
+        match name.split_once("::") {
+            Some((identifier, case)) => (identifier, Some(case)),
+            None => (name, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compilers::mode::Mode;
+    use crate::compilers::solidity::mode::Mode as SolidityMode;
+    use crate::summary::report::record::Record;
+    use crate::summary::report::record::RecordOutcome;
+
+    use super::Rerun;
+
+    fn mode(solc_optimize: bool) -> Mode {
+        SolidityMode::new(
+            semver::Version::new(0, 8, 24),
+            era_compiler_solidity::SolcPipeline::Yul,
+            false,
+            solc_optimize,
+            era_compiler_llvm_context::OptimizerSettings::cycles(),
+            false,
+            false,
+        )
+        .into()
+    }
+
+    fn report(records: Vec<Record>) -> String {
+        records
+            .iter()
+            .map(|record| serde_json::to_string(record).expect("Always valid"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn split_name() {
+        assert_eq!(Rerun::split_name("tests/foo.sol"), ("tests/foo.sol", None));
+        assert_eq!(
+            Rerun::split_name("tests/foo.sol::main"),
+            ("tests/foo.sol", Some("main"))
+        );
+        assert_eq!(
+            Rerun::split_name("tests/foo.sol::main[#deployer:Test]"),
+            ("tests/foo.sol", Some("main"))
+        );
+        assert_eq!(
+            Rerun::split_name("tests/foo.sol[Test:0]"),
+            ("tests/foo.sol", None)
+        );
+    }
+
+    #[test]
+    fn failed_cases() {
+        let contents = report(vec![
+            Record::new(
+                "tests/failed.sol::main[#deployer:Test]".to_owned(),
+                Some(mode(true).to_string()),
+                RecordOutcome::Failed,
+            ),
+            Record::new(
+                "tests/passed.sol::main".to_owned(),
+                Some(mode(true).to_string()),
+                RecordOutcome::Passed,
+            ),
+        ]);
+        let rerun = Rerun::try_from_json_lines(contents.as_str()).expect("Always valid");
+
+        assert!(rerun.contains_test("tests/failed.sol"));
+        assert!(rerun.contains_mode("tests/failed.sol", &mode(true)));
+        assert!(!rerun.contains_mode("tests/failed.sol", &mode(false)));
+        assert!(rerun.contains_case("tests/failed.sol", "main", &mode(true)));
+        assert!(!rerun.contains_case("tests/failed.sol", "other", &mode(true)));
+        assert!(!rerun.contains_test("tests/passed.sol"));
+    }
+
+    #[test]
+    fn expected_failures_skipped() {
+        let mut record = Record::new(
+            "tests/foo.sol::main".to_owned(),
+            Some(mode(true).to_string()),
+            RecordOutcome::Failed,
+        );
+        record.is_expected_failure = true;
+        let contents = report(vec![record]);
+        let rerun = Rerun::try_from_json_lines(contents.as_str()).expect("Always valid");
+
+        assert!(!rerun.contains_test("tests/foo.sol"));
+    }
+
+    #[test]
+    fn without_mode() {
+        let contents = report(vec![Record::new(
+            "tests/foo.sol[Test:0]".to_owned(),
+            None,
+            RecordOutcome::Invalid,
+        )]);
+        let rerun = Rerun::try_from_json_lines(contents.as_str()).expect("Always valid");
+
+        assert!(rerun.contains_mode("tests/foo.sol", &mode(true)));
+        assert!(rerun.contains_mode("tests/foo.sol", &mode(false)));
+        assert!(rerun.contains_case("tests/foo.sol", "main", &mode(true)));
+        assert!(rerun.contains_case("tests/foo.sol", "other", &mode(false)));
+    }
+
+    #[test]
+    fn error_invalid_line() {
+        assert!(Rerun::try_from_json_lines("\n{}\n").is_err());
+    }
+}
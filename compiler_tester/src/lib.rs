@@ -7,13 +7,17 @@
 #![allow(clippy::too_many_arguments)]
 
 pub(crate) mod compilers;
+pub(crate) mod corpus;
 pub(crate) mod directories;
 pub(crate) mod filters;
+pub(crate) mod listing;
+pub(crate) mod shard;
 pub(crate) mod summary;
 pub(crate) mod target;
 pub(crate) mod test;
 pub(crate) mod utils;
 pub(crate) mod vm;
+pub(crate) mod worker;
 pub(crate) mod workflow;
 
 use std::path::Path;
@@ -24,9 +28,17 @@ use itertools::Itertools;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::corpus::collection::Collection as CorpusCollection;
+use crate::corpus::language::Language as CorpusLanguage;
+use crate::listing::entry::Entry as ListingEntry;
+use crate::worker::Worker;
+
+pub use crate::compilers::cache::persistent::PersistentCache;
 pub use crate::compilers::eravm::EraVMCompiler;
 pub use crate::compilers::llvm::LLVMCompiler;
+pub use crate::compilers::mode::descriptor::Descriptor as ModeDescriptor;
 pub use crate::compilers::mode::llvm_options::LLVMOptions;
+pub use crate::compilers::mode::specification::Specification as ModeSpecification;
 pub use crate::compilers::mode::Mode;
 pub use crate::compilers::solidity::mode::Mode as SolidityMode;
 pub use crate::compilers::solidity::upstream::SolidityCompiler as SolidityUpstreamCompiler;
@@ -34,19 +46,30 @@ pub use crate::compilers::solidity::SolidityCompiler;
 pub use crate::compilers::vyper::VyperCompiler;
 pub use crate::compilers::yul::YulCompiler;
 pub use crate::compilers::Compiler;
+pub use crate::corpus::Corpus;
 pub use crate::directories::ethereum::test::EthereumTest;
 pub use crate::directories::ethereum::EthereumDirectory;
 pub use crate::directories::matter_labs::MatterLabsDirectory;
 pub use crate::directories::Buildable;
 pub use crate::directories::Collection;
+pub use crate::filters::rerun::Rerun;
 pub use crate::filters::Filters;
+pub use crate::listing::format::Format as ListingFormat;
+pub use crate::listing::Listing;
+pub use crate::shard::Shard;
+pub use crate::summary::baseline::Baseline;
+pub use crate::summary::report::format::Format as ReportFormat;
+pub use crate::summary::report::Report;
 pub use crate::summary::Summary;
 pub use crate::target::Target;
+pub use crate::vm::chain_context::ChainContext;
 pub use crate::vm::eravm::deployers::dummy_deployer::DummyDeployer as EraVMNativeDeployer;
 pub use crate::vm::eravm::deployers::system_contract_deployer::SystemContractDeployer as EraVMSystemContractDeployer;
 pub use crate::vm::eravm::deployers::EraVMDeployer;
 pub use crate::vm::eravm::EraVM;
 pub use crate::vm::evm::EVM;
+pub use crate::vm::limits::Limits;
+pub use crate::worker::isolation::Isolation;
 pub use crate::workflow::Workflow;
 
 /// The debug directory path.
@@ -68,10 +91,16 @@ pub struct CompilerTester {
     pub summary: Arc<Mutex<Summary>>,
     /// The filters.
     pub filters: Filters,
+    /// The shard to run, if the test suite is split between several machines.
+    pub shard: Option<Shard>,
     /// The debug config.
     pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     /// Actions to perform.
     pub workflow: Workflow,
+    /// The process isolation role, if the tests are run in worker processes.
+    pub isolation: Option<Isolation>,
+    /// The additional test corpora.
+    pub corpora: Vec<Corpus>,
 }
 
 impl CompilerTester {
@@ -106,17 +135,30 @@ impl CompilerTester {
     pub fn new(
         summary: Arc<Mutex<Summary>>,
         filters: Filters,
+        shard: Option<Shard>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
         workflow: Workflow,
+        isolation: Option<Isolation>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             summary,
             filters,
+            shard,
             debug_config,
             workflow,
+            isolation,
+            corpora: vec![],
         })
     }
 
+    ///
+    /// Adds the additional test corpora to the built-in ones.
+    ///
+    pub fn with_corpora(mut self, corpora: Vec<Corpus>) -> Self {
+        self.corpora = corpora;
+        self
+    }
+
     ///
     /// Runs all tests on EraVM.
     ///
@@ -127,62 +169,55 @@ impl CompilerTester {
         let tests = self.all_tests(false)?;
         let vm = Arc::new(vm);
 
-        let _: Vec<()> = tests
-            .into_par_iter()
-            .map(|(test, compiler, mode)| {
-                let mode_string = mode.to_string();
-                let specialized_debug_config = self
-                    .debug_config
-                    .as_ref()
-                    .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
-                if let Some(test) = test.build_for_eravm(
-                    mode,
-                    compiler,
-                    Target::EraVM,
-                    self.summary.clone(),
-                    &self.filters,
-                    specialized_debug_config,
-                ) {
-                    if let Workflow::BuildAndRun = self.workflow {
-                        test.run_eravm::<D, M>(self.summary.clone(), vm.clone())
-                    };
-                }
-            })
-            .collect();
-
-        Ok(())
+        self.run_tests(tests, |(test, compiler, mode)| {
+            let mode_string = mode.to_string();
+            let specialized_debug_config = self
+                .debug_config
+                .as_ref()
+                .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
+            if let Some(test) = test.build_for_eravm(
+                mode,
+                compiler,
+                Target::EraVM,
+                self.summary.clone(),
+                &self.filters,
+                specialized_debug_config,
+            ) {
+                if let Workflow::BuildAndRun = self.workflow {
+                    test.run_eravm::<D, M>(self.summary.clone(), vm.clone())
+                };
+            }
+        })
     }
 
     ///
     /// Runs all tests on EVM.
     ///
-    pub fn run_evm(self, use_upstream_solc: bool) -> anyhow::Result<()> {
+    /// If `check_gas` is set, the Ethereum test gas expectations are checked for the upstream
+    /// `solc` modes targeting the `Shanghai` EVM version, which the native EVM executes.
+    ///
+    pub fn run_evm(self, use_upstream_solc: bool, check_gas: bool) -> anyhow::Result<()> {
         let tests = self.all_tests(use_upstream_solc)?;
 
-        let _: Vec<()> = tests
-            .into_par_iter()
-            .map(|(test, compiler, mode)| {
-                let mode_string = mode.to_string();
-                let specialized_debug_config = self
-                    .debug_config
-                    .as_ref()
-                    .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
-                if let Some(test) = test.build_for_evm(
-                    mode,
-                    compiler,
-                    Target::EVM,
-                    self.summary.clone(),
-                    &self.filters,
-                    specialized_debug_config,
-                ) {
-                    if let Workflow::BuildAndRun = self.workflow {
-                        test.run_evm(self.summary.clone())
-                    };
-                }
-            })
-            .collect();
-
-        Ok(())
+        self.run_tests(tests, |(test, compiler, mode)| {
+            let mode_string = mode.to_string();
+            let specialized_debug_config = self
+                .debug_config
+                .as_ref()
+                .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
+            if let Some(test) = test.build_for_evm(
+                mode,
+                compiler,
+                Target::EVM,
+                self.summary.clone(),
+                &self.filters,
+                specialized_debug_config,
+            ) {
+                if let Workflow::BuildAndRun = self.workflow {
+                    test.run_evm(self.summary.clone(), check_gas)
+                };
+            }
+        })
     }
 
     ///
@@ -199,25 +234,63 @@ impl CompilerTester {
         let tests = self.all_tests(use_upstream_solc)?;
         let vm = Arc::new(vm);
 
-        let _: Vec<()> = tests
-            .into_par_iter()
-            .map(|(test, compiler, mode)| {
-                if let Some(test) = test.build_for_evm(
-                    mode,
-                    compiler,
-                    Target::EVMInterpreter,
-                    self.summary.clone(),
-                    &self.filters,
-                    self.debug_config.clone(),
-                ) {
-                    if let Workflow::BuildAndRun = self.workflow {
-                        test.run_evm_interpreter::<D, M>(self.summary.clone(), vm.clone());
-                    }
+        self.run_tests(tests, |(test, compiler, mode)| {
+            if let Some(test) = test.build_for_evm(
+                mode,
+                compiler,
+                Target::EVMInterpreter,
+                self.summary.clone(),
+                &self.filters,
+                self.debug_config.clone(),
+            ) {
+                if let Workflow::BuildAndRun = self.workflow {
+                    test.run_evm_interpreter::<D, M>(self.summary.clone(), vm.clone());
                 }
+            }
+        })
+    }
+
+    ///
+    /// Lists the scheduled (test, case, mode) triples without building or running them.
+    ///
+    pub fn list(self, target: Target, use_upstream_solc: bool) -> anyhow::Result<Listing> {
+        let tests = self.all_tests(use_upstream_solc)?;
+
+        let entries = tests
+            .into_par_iter()
+            .flat_map_iter(|(test, _compiler, mode)| {
+                let cases = test.list(&mode, target, &self.filters);
+                let (identifier, compiler, mode) = (
+                    test.identifier().to_owned(),
+                    mode.compiler_name().to_owned(),
+                    mode.to_string(),
+                );
+                cases.into_iter().map(move |case| {
+                    ListingEntry::new(identifier.clone(), case, compiler.clone(), mode.clone())
+                })
             })
             .collect();
+        Ok(Listing::new(entries))
+    }
 
-        Ok(())
+    ///
+    /// Runs the tests in-process on the thread pool, or in worker processes if isolation is enabled.
+    ///
+    fn run_tests<F>(&self, tests: Vec<Test>, f: F) -> anyhow::Result<()>
+    where
+        F: Fn(Test) + Sync + Send,
+    {
+        match self.isolation {
+            Some(Isolation::Parent {
+                workers,
+                ref arguments,
+            }) => Worker::dispatch(self.summary.clone(), tests, workers, arguments.as_slice()),
+            Some(Isolation::Worker) => Worker::serve(self.summary.clone(), tests, f),
+            None => {
+                let _: Vec<()> = tests.into_par_iter().map(f).collect();
+                Ok(())
+            }
+        }
     }
 
     ///
@@ -234,7 +307,7 @@ impl CompilerTester {
         let mut tests = Vec::with_capacity(16384);
 
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::SOLIDITY_SIMPLE,
+            Path::new(Self::SOLIDITY_SIMPLE),
             era_compiler_common::EXTENSION_SOLIDITY,
             if use_upstream_solc {
                 solidity_upstream_compiler.clone()
@@ -243,28 +316,28 @@ impl CompilerTester {
             },
         )?);
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::VYPER_SIMPLE,
+            Path::new(Self::VYPER_SIMPLE),
             era_compiler_common::EXTENSION_VYPER,
             vyper_compiler.clone(),
         )?);
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::YUL_SIMPLE,
+            Path::new(Self::YUL_SIMPLE),
             era_compiler_common::EXTENSION_YUL,
-            yul_compiler,
+            yul_compiler.clone(),
         )?);
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::LLVM_SIMPLE,
+            Path::new(Self::LLVM_SIMPLE),
             era_compiler_common::EXTENSION_LLVM_SOURCE,
-            llvm_compiler,
+            llvm_compiler.clone(),
         )?);
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::ERAVM_SIMPLE,
+            Path::new(Self::ERAVM_SIMPLE),
             era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
-            eravm_compiler,
+            eravm_compiler.clone(),
         )?);
 
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::SOLIDITY_COMPLEX,
+            Path::new(Self::SOLIDITY_COMPLEX),
             era_compiler_common::EXTENSION_JSON,
             if use_upstream_solc {
                 solidity_upstream_compiler.clone()
@@ -273,13 +346,13 @@ impl CompilerTester {
             },
         )?);
         tests.extend(self.directory::<MatterLabsDirectory>(
-            Self::VYPER_COMPLEX,
+            Path::new(Self::VYPER_COMPLEX),
             era_compiler_common::EXTENSION_JSON,
             vyper_compiler.clone(),
         )?);
 
         tests.extend(self.directory::<EthereumDirectory>(
-            Self::SOLIDITY_ETHEREUM,
+            Path::new(Self::SOLIDITY_ETHEREUM),
             era_compiler_common::EXTENSION_SOLIDITY,
             if use_upstream_solc {
                 solidity_upstream_compiler.clone()
@@ -288,11 +361,38 @@ impl CompilerTester {
             },
         )?);
         tests.extend(self.directory::<EthereumDirectory>(
-            Self::VYPER_ETHEREUM,
+            Path::new(Self::VYPER_ETHEREUM),
             era_compiler_common::EXTENSION_VYPER,
-            vyper_compiler,
+            vyper_compiler.clone(),
         )?);
 
+        for corpus in self.corpora.iter() {
+            let compiler: Arc<dyn Compiler> = match corpus.language {
+                CorpusLanguage::Solidity if use_upstream_solc => solidity_upstream_compiler.clone(),
+                CorpusLanguage::Solidity => solidity_compiler.clone(),
+                CorpusLanguage::Vyper => vyper_compiler.clone(),
+                CorpusLanguage::Yul => yul_compiler.clone(),
+                CorpusLanguage::LLVM => llvm_compiler.clone(),
+                CorpusLanguage::EraVM => eravm_compiler.clone(),
+            };
+            tests.extend(match corpus.collection {
+                CorpusCollection::MatterLabsDirectory => self.directory::<MatterLabsDirectory>(
+                    corpus.path.as_path(),
+                    corpus.extension(),
+                    compiler,
+                )?,
+                CorpusCollection::EthereumDirectory => self.directory::<EthereumDirectory>(
+                    corpus.path.as_path(),
+                    corpus.extension(),
+                    compiler,
+                )?,
+            });
+        }
+
+        if let Some(shard) = self.shard {
+            tests.retain(|(test, _, mode)| shard.contains(test.identifier(), mode));
+        }
+
         Ok(tests)
     }
 
@@ -301,26 +401,27 @@ impl CompilerTester {
     ///
     fn directory<T>(
         &self,
-        path: &str,
-        extension: &'static str,
+        path: &Path,
+        extension: &str,
         compiler: Arc<dyn Compiler>,
     ) -> anyhow::Result<Vec<Test>>
     where
         T: Collection,
     {
-        Ok(T::read_all(
-            Path::new(path),
-            extension,
-            self.summary.clone(),
-            &self.filters,
+        Ok(
+            T::read_all(path, extension, self.summary.clone(), &self.filters)
+                .map_err(|error| {
+                    anyhow::anyhow!(
+                        "Failed to read the tests directory `{}`: {}",
+                        path.to_string_lossy(),
+                        error
+                    )
+                })?
+                .into_iter()
+                .map(|test| Arc::new(test) as Arc<dyn Buildable>)
+                .cartesian_product(compiler.all_modes())
+                .map(|(test, mode)| (test, compiler.clone() as Arc<dyn Compiler>, mode))
+                .collect(),
         )
-        .map_err(|error| {
-            anyhow::anyhow!("Failed to read the tests directory `{}`: {}", path, error)
-        })?
-        .into_iter()
-        .map(|test| Arc::new(test) as Arc<dyn Buildable>)
-        .cartesian_product(compiler.all_modes())
-        .map(|(test, mode)| (test, compiler.clone() as Arc<dyn Compiler>, mode))
-        .collect())
     }
 }
@@ -0,0 +1,36 @@
+//!
+//! The compiler tester listing entry.
+//!
+
+use serde::Serialize;
+
+///
+/// The compiler tester listing entry.
+///
+/// Corresponds to a single scheduled (test, case, mode) triple.
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct Entry {
+    /// The test identifier.
+    pub test: String,
+    /// The case name, unset for the tests with a single anonymous case.
+    pub case: Option<String>,
+    /// The compiler name.
+    pub compiler: String,
+    /// The mode.
+    pub mode: String,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(test: String, case: Option<String>, compiler: String, mode: String) -> Self {
+        Self {
+            test,
+            case,
+            compiler,
+            mode,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//!
+//! The compiler tester listing format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The compiler tester listing format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The plain text listing, one entry per line.
+    Text,
+    /// The JSON listing.
+    JSON,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::JSON),
+            _ => Err("Could not parse listing format. Supported formats: text, json."),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::JSON => write!(f, "json"),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//!
+//! The compiler tester listing.
+//!
+
+pub mod entry;
+pub mod format;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use self::entry::Entry;
+use self::format::Format;
+
+///
+/// The compiler tester listing.
+///
+/// Enumerates the scheduled (test, case, mode) triples without building or running them.
+///
+#[derive(Debug, Default, Serialize)]
+pub struct Listing {
+    /// The scheduled entries.
+    pub entries: Vec<Entry>,
+    /// The number of entries per compiler.
+    pub compilers: BTreeMap<String, usize>,
+    /// The number of entries per mode.
+    pub modes: BTreeMap<String, usize>,
+}
+
+impl Listing {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(mut entries: Vec<Entry>) -> Self {
+        entries.sort_by(|a, b| {
+            (a.test.as_str(), a.case.as_deref(), a.mode.as_str()).cmp(&(
+                b.test.as_str(),
+                b.case.as_deref(),
+                b.mode.as_str(),
+            ))
+        });
+
+        let mut compilers = BTreeMap::new();
+        let mut modes = BTreeMap::new();
+        for entry in entries.iter() {
+            *compilers.entry(entry.compiler.to_owned()).or_insert(0) += 1;
+            *modes.entry(entry.mode.to_owned()).or_insert(0) += 1;
+        }
+
+        Self {
+            entries,
+            compilers,
+            modes,
+        }
+    }
+
+    ///
+    /// Serializes the listing in the specified format.
+    ///
+    pub fn serialize(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::JSON => serde_json::to_string_pretty(self).expect("Always valid"),
+        }
+    }
+
+    ///
+    /// Serializes the listing into plain text, one entry per line, followed by the counts.
+    ///
+    fn to_text(&self) -> String {
+        let mut text = String::with_capacity(self.entries.len() * 128);
+        for entry in self.entries.iter() {
+            let name = match entry.case {
+                Some(ref case) => format!("{}::{}", entry.test, case),
+                None => entry.test.to_owned(),
+            };
+            writeln!(text, "{:24} {}", entry.mode, name).expect("Always valid");
+        }
+
+        writeln!(text).expect("Always valid");
+        writeln!(text, "Compilers:").expect("Always valid");
+        for (compiler, count) in self.compilers.iter() {
+            writeln!(text, "    {compiler:24} {count}").expect("Always valid");
+        }
+        writeln!(text, "Modes:").expect("Always valid");
+        for (mode, count) in self.modes.iter() {
+            writeln!(text, "    {mode:24} {count}").expect("Always valid");
+        }
+        writeln!(text, "Total: {}", self.entries.len()).expect("Always valid");
+        text
+    }
+}
@@ -0,0 +1,132 @@
+//!
+//! The compiler tester shard.
+//!
+
+use sha3::Digest;
+
+use crate::compilers::mode::Mode;
+
+///
+/// The compiler tester shard.
+///
+/// Deterministically selects a subset of the (test, mode) pairs, so the full test suite
+/// can be split between several machines.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Shard {
+    /// The zero-based shard index.
+    index: usize,
+    /// The total number of shards.
+    count: usize,
+}
+
+impl Shard {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(index: usize, count: usize) -> anyhow::Result<Self> {
+        if count == 0 {
+            anyhow::bail!("The shard count must be positive");
+        }
+        if index >= count {
+            anyhow::bail!(
+                "The shard index {} is out of range for the shard count {}",
+                index,
+                count
+            );
+        }
+        Ok(Self { index, count })
+    }
+
+    ///
+    /// Whether the test in the specified mode belongs to the shard.
+    ///
+    /// The hash is computed from the test identifier and the mode string, so it is stable
+    /// across runs, machines, and thread counts.
+    ///
+    pub fn contains(&self, identifier: &str, mode: &Mode) -> bool {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(identifier.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(mode.to_string().as_bytes());
+        let hash = hasher.finalize();
+
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&hash[..8]);
+        (u64::from_be_bytes(bytes) % (self.count as u64)) as usize == self.index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compilers::mode::Mode;
+    use crate::compilers::solidity::mode::Mode as SolidityMode;
+
+    use super::Shard;
+
+    fn modes() -> Vec<Mode> {
+        [
+            (era_compiler_solidity::SolcPipeline::Yul, false, true),
+            (era_compiler_solidity::SolcPipeline::Yul, false, false),
+            (era_compiler_solidity::SolcPipeline::EVMLA, false, true),
+            (era_compiler_solidity::SolcPipeline::EVMLA, true, true),
+        ]
+        .into_iter()
+        .map(|(pipeline, via_ir, solc_optimize)| {
+            SolidityMode::new(
+                semver::Version::new(0, 8, 24),
+                pipeline,
+                via_ir,
+                solc_optimize,
+                era_compiler_llvm_context::OptimizerSettings::cycles(),
+                false,
+                false,
+            )
+            .into()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn partition() {
+        let modes = modes();
+        for count in [1, 2, 3, 7] {
+            let shards = (0..count)
+                .map(|index| Shard::new(index, count).expect("Always valid"))
+                .collect::<Vec<Shard>>();
+            for test in 0..100 {
+                let identifier = format!("tests/solidity/simple/test_{test}.sol");
+                for mode in modes.iter() {
+                    let owners = shards
+                        .iter()
+                        .filter(|shard| shard.contains(identifier.as_str(), mode))
+                        .count();
+                    assert_eq!(owners, 1, "{identifier} {mode} in {count} shards");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stable() {
+        let mode = modes().remove(0);
+        let first = Shard::new(1, 3).expect("Always valid");
+        let second = Shard::new(1, 3).expect("Always valid");
+        for test in 0..100 {
+            let identifier = format!("tests/solidity/simple/test_{test}.sol");
+            assert_eq!(
+                first.contains(identifier.as_str(), &mode),
+                second.contains(identifier.as_str(), &mode)
+            );
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        assert!(Shard::new(0, 1).is_ok());
+        assert!(Shard::new(2, 3).is_ok());
+        assert!(Shard::new(0, 0).is_err());
+        assert!(Shard::new(3, 3).is_err());
+        assert!(Shard::new(4, 3).is_err());
+    }
+}
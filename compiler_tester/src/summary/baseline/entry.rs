@@ -0,0 +1,114 @@
+//!
+//! The known failures baseline entry.
+//!
+
+use serde::Deserialize;
+
+use crate::compilers::mode::specification::Specification;
+use crate::compilers::mode::Mode;
+use crate::target::Target;
+
+///
+/// The known failures baseline entry.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Entry {
+    /// The test name prefix, e.g. a test path or a full case name.
+    /// It must be followed by the end of the name, `::`, or `[` in the matched names.
+    pub name: String,
+    /// The mode filter in the metadata format, e.g. `Y+ >=0.8.20`. Matches any mode if unset.
+    pub mode: Option<Specification>,
+    /// The target. Matches any target if unset.
+    pub target: Option<Target>,
+    /// The comment, usually a reference to the tracked issue.
+    pub comment: Option<String>,
+}
+
+impl Entry {
+    ///
+    /// Checks whether the summary element with the specified name and mode is covered by the entry.
+    ///
+    pub fn matches(&self, name: &str, mode: Option<&Mode>) -> bool {
+        match name.strip_prefix(self.name.as_str()) {
+            Some(rest) if rest.is_empty() || rest.starts_with("::") || rest.starts_with('[') => {}
+            _ => return false,
+        }
+
+        match (self.mode.as_ref(), mode) {
+            (None, _) => true,
+            (Some(filter), Some(mode)) => mode.check_filters(std::slice::from_ref(filter)),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::compilers::mode::specification::Specification;
+    use crate::compilers::mode::Mode;
+    use crate::compilers::solidity::mode::Mode as SolidityMode;
+
+    use super::Entry;
+
+    fn entry(name: &str, mode: Option<&str>) -> Entry {
+        Entry {
+            name: name.to_owned(),
+            mode: mode.map(|mode| Specification::from_str(mode).expect("Always valid")),
+            target: None,
+            comment: None,
+        }
+    }
+
+    fn mode(solc_optimize: bool) -> Mode {
+        SolidityMode::new(
+            semver::Version::new(0, 8, 24),
+            era_compiler_solidity::SolcPipeline::Yul,
+            false,
+            solc_optimize,
+            era_compiler_llvm_context::OptimizerSettings::cycles(),
+            false,
+            false,
+        )
+        .into()
+    }
+
+    #[test]
+    fn name_boundary() {
+        let entry = entry("tests/foo.sol", None);
+
+        assert!(entry.matches("tests/foo.sol", None));
+        assert!(entry.matches("tests/foo.sol::main", None));
+        assert!(entry.matches("tests/foo.sol::main[#deployer:Test]", None));
+        assert!(entry.matches("tests/foo.sol[Test:0]", None));
+        assert!(!entry.matches("tests/foo.sol2", None));
+        assert!(!entry.matches("tests/foo.sol.bak::main", None));
+        assert!(!entry.matches("tests/fo", None));
+    }
+
+    #[test]
+    fn case_name() {
+        let entry = entry("tests/foo.sol::main", None);
+
+        assert!(entry.matches("tests/foo.sol::main", None));
+        assert!(entry.matches("tests/foo.sol::main[#deployer:Test]", None));
+        assert!(!entry.matches("tests/foo.sol::main_2", None));
+        assert!(!entry.matches("tests/foo.sol::other", None));
+    }
+
+    #[test]
+    fn mode_filter() {
+        let entry = entry("tests/foo.sol", Some("Y+ >=0.8.20"));
+
+        assert!(entry.matches("tests/foo.sol::main", Some(&mode(true))));
+        assert!(!entry.matches("tests/foo.sol::main", Some(&mode(false))));
+    }
+
+    #[test]
+    fn no_mode() {
+        assert!(entry("tests/foo.sol", None).matches("tests/foo.sol", None));
+        assert!(entry("tests/foo.sol", None).matches("tests/foo.sol", Some(&mode(true))));
+        assert!(!entry("tests/foo.sol", Some("Y+")).matches("tests/foo.sol", None));
+    }
+}
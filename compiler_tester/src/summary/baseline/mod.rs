@@ -0,0 +1,47 @@
+//!
+//! The known failures baseline.
+//!
+
+pub mod entry;
+
+use std::path::Path;
+
+use crate::compilers::mode::Mode;
+use crate::target::Target;
+
+use self::entry::Entry;
+
+///
+/// The known failures baseline.
+///
+/// Lists the tests which are expected to fail, so they are reported as `XFAIL` instead of
+/// failing the run, and as `XPASS` if all their elements in a case and mode unexpectedly pass.
+///
+#[derive(Debug, Default)]
+pub struct Baseline {
+    /// The entries applicable to the current target.
+    entries: Vec<Entry>,
+}
+
+impl Baseline {
+    ///
+    /// Reads the baseline file, keeping only the entries applicable to `target`.
+    ///
+    /// The file is a YAML or JSON list of entries.
+    ///
+    pub fn try_from_path(path: &Path, target: Target) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path)
+            .map_err(|error| anyhow::anyhow!("Baseline file {:?} opening: {}", path, error))?;
+        let mut entries: Vec<Entry> = serde_yaml::from_reader(std::io::BufReader::new(file))
+            .map_err(|error| anyhow::anyhow!("Baseline file {:?} parsing: {}", path, error))?;
+        entries.retain(|entry| entry.target.is_none() || entry.target == Some(target));
+        Ok(Self { entries })
+    }
+
+    ///
+    /// Checks whether the summary element with the specified name and mode is expected to fail.
+    ///
+    pub fn contains(&self, name: &str, mode: Option<&Mode>) -> bool {
+        self.entries.iter().any(|entry| entry.matches(name, mode))
+    }
+}
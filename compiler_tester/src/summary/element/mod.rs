@@ -7,6 +7,7 @@ pub mod outcome;
 use colored::Colorize;
 
 use crate::compilers::mode::Mode;
+use crate::test::case::input::output::revert::Revert;
 
 use self::outcome::passed_variant::PassedVariant;
 use self::outcome::Outcome;
@@ -22,6 +23,8 @@ pub struct Element {
     pub name: String,
     /// The test outcome.
     pub outcome: Outcome,
+    /// Whether the test is listed in the known failures baseline.
+    pub is_expected_failure: bool,
 }
 
 impl Element {
@@ -33,6 +36,7 @@ impl Element {
             mode,
             name,
             outcome,
+            is_expected_failure: false,
         }
     }
 
@@ -43,13 +47,19 @@ impl Element {
         match self.outcome {
             Outcome::Passed { .. } if !verbosity => return None,
             Outcome::Ignored => return None,
+            _ if !verbosity && self.is_expected_failure => return None,
             _ => {}
         }
 
         let outcome = match self.outcome {
             Outcome::Passed { .. } => "PASSED".green(),
+            _ if self.is_expected_failure => "XFAIL".yellow(),
             Outcome::Failed { .. } => "FAILED".bright_red(),
+            Outcome::GasMismatch { .. } => "GASDIFF".bright_red(),
+            Outcome::LimitExceeded { .. } => "FAILED".bright_red(),
+            Outcome::Diverged { .. } => "DIVERGED".bright_red(),
             Outcome::Invalid { .. } => "INVALID".red(),
+            Outcome::Crashed { .. } => "CRASHED".bright_red(),
             Outcome::Ignored => "IGNORED".bright_black(),
         };
 
@@ -87,17 +97,42 @@ impl Element {
                 ref expected,
                 ref found,
                 ref calldata,
+                ..
             } => {
-                format!(
-                    "(expected {}, found {}, calldata {})",
-                    ron::ser::to_string_pretty(expected, ron::ser::PrettyConfig::default())
-                        .expect("Always valid"),
-                    ron::ser::to_string_pretty(found, ron::ser::PrettyConfig::default())
-                        .expect("Always valid"),
-                    calldata,
-                )
+                let mut details = Vec::with_capacity(5);
+                for (prefix, output) in [("expected", expected), ("found", found)] {
+                    details.push(format!(
+                        "{prefix} {}",
+                        ron::ser::to_string_pretty(output, ron::ser::PrettyConfig::default())
+                            .expect("Always valid")
+                    ));
+                    if !output.exception {
+                        continue;
+                    }
+                    if let Some(revert) = Revert::decode(
+                        output.return_data.as_slice(),
+                        expected.custom_errors.as_slice(),
+                    ) {
+                        details.push(format!("{prefix} revert {revert}"));
+                    }
+                }
+                details.push(format!("calldata {calldata}"));
+                format!("({})", details.join(", "))
             }
+            Outcome::GasMismatch {
+                expected,
+                found,
+                ref calldata,
+                ..
+            } => format!("(expected gas {expected}, found gas {found}, calldata {calldata})"),
+            Outcome::LimitExceeded {
+                ref reason,
+                ref calldata,
+                ..
+            } => format!("({reason}, calldata {calldata})"),
+            Outcome::Diverged { ref divergence, .. } => divergence.to_string(),
             Outcome::Invalid { ref error } => error.to_string(),
+            Outcome::Crashed { ref reason } => reason.to_string(),
             _ => String::new(),
         };
 
@@ -4,6 +4,9 @@
 
 pub mod passed_variant;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::test::case::input::output::Output;
 
 use self::passed_variant::PassedVariant;
@@ -11,7 +14,7 @@ use self::passed_variant::PassedVariant;
 ///
 /// The compiler tester summary element outcome.
 ///
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum Outcome {
     /// The `passed` outcome.
     Passed {
@@ -28,12 +31,46 @@ pub enum Outcome {
         found: Output,
         /// The calldata.
         calldata: String,
+        /// The test group name.
+        group: Option<String>,
+    },
+    /// The `gas mismatch` outcome. The output is correct, but the gas usage differs from expected.
+    GasMismatch {
+        /// The expected gas, which is not limited to the actual gas type.
+        expected: web3::types::U256,
+        /// The actual gas.
+        found: u64,
+        /// The calldata.
+        calldata: String,
+        /// The test group name.
+        group: Option<String>,
+    },
+    /// The `limit exceeded` outcome. The execution has exceeded the cycles, ergs, gas, or time limit.
+    LimitExceeded {
+        /// The exceeded limit description.
+        reason: String,
+        /// The calldata.
+        calldata: String,
+        /// The test group name.
+        group: Option<String>,
+    },
+    /// The `diverged` outcome. The reference VM and vm2 produced different results.
+    Diverged {
+        /// The divergence description.
+        divergence: String,
+        /// The test group name.
+        group: Option<String>,
     },
     /// The `invalid` outcome. The test is incorrect.
     Invalid {
         /// The building error description.
         error: String,
     },
+    /// The `crashed` outcome. The worker process running the test has crashed.
+    Crashed {
+        /// The exit status and the worker error output.
+        reason: String,
+    },
     /// The `ignored` outcome. The test is ignored.
     Ignored,
 }
@@ -49,14 +86,55 @@ impl Outcome {
     ///
     /// A shortcut constructor.
     ///
-    pub fn failed(expected: Output, found: Output, calldata: Vec<u8>) -> Self {
+    pub fn failed(
+        group: Option<String>,
+        expected: Output,
+        found: Output,
+        calldata: Vec<u8>,
+    ) -> Self {
         Self::Failed {
             expected,
             found,
             calldata: hex::encode(calldata.as_slice()),
+            group,
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn gas_mismatch(
+        group: Option<String>,
+        expected: web3::types::U256,
+        found: u64,
+        calldata: Vec<u8>,
+    ) -> Self {
+        Self::GasMismatch {
+            expected,
+            found,
+            calldata: hex::encode(calldata.as_slice()),
+            group,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn limit_exceeded(group: Option<String>, reason: String, calldata: Vec<u8>) -> Self {
+        Self::LimitExceeded {
+            reason,
+            calldata: hex::encode(calldata.as_slice()),
+            group,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn diverged(group: Option<String>, divergence: String) -> Self {
+        Self::Diverged { divergence, group }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -69,6 +147,13 @@ impl Outcome {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn crashed(reason: String) -> Self {
+        Self::Crashed { reason }
+    }
+
     ///
     /// A shortcut constructor.
     ///
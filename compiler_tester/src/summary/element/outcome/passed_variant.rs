@@ -2,10 +2,13 @@
 //! The compiler tester summary element passed outcome variant.
 //!
 
+use serde::Deserialize;
+use serde::Serialize;
+
 ///
 /// The compiler tester summary element passed outcome variant.
 ///
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum PassedVariant {
     /// The contract deploy.
     Deploy {
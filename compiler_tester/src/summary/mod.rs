@@ -2,19 +2,26 @@
 //! The compiler tester summary.
 //!
 
+pub mod baseline;
 pub mod element;
+pub mod report;
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use colored::Colorize;
 
 use crate::compilers::mode::Mode;
+use crate::filters::rerun::Rerun;
 use crate::test::case::input::output::Output;
 
+use self::baseline::Baseline;
 use self::element::outcome::passed_variant::PassedVariant;
 use self::element::outcome::Outcome;
 use self::element::Element;
+use self::report::record::Record;
+use self::report::Report;
 
 ///
 /// The compiler tester summary.
@@ -27,14 +34,25 @@ pub struct Summary {
     verbosity: bool,
     /// Whether the output is suppressed.
     quiet: bool,
+    /// The known failures baseline.
+    baseline: Baseline,
     /// The passed tests counter.
     passed: usize,
     /// The failed tests counter.
     failed: usize,
+    /// The gas mismatched tests counter.
+    gas_mismatched: usize,
+    /// The diverged tests counter.
+    diverged: usize,
     /// The invalid tests counter.
     invalid: usize,
+    /// The crashed tests counter.
+    crashed: usize,
     /// The ignored tests counter.
     ignored: usize,
+    /// The known failures from the baseline, keyed by the test identifier, case, and mode.
+    /// The value is whether any element of the case has failed in the mode.
+    expected_failures: BTreeMap<(String, Option<String>, Option<String>), bool>,
 }
 
 impl Summary {
@@ -49,22 +67,45 @@ impl Summary {
             elements: Vec::with_capacity(Self::ELEMENTS_INITIAL_CAPACITY),
             verbosity,
             quiet,
+            baseline: Baseline::default(),
             passed: 0,
             failed: 0,
+            gas_mismatched: 0,
+            diverged: 0,
             invalid: 0,
+            crashed: 0,
             ignored: 0,
+            expected_failures: BTreeMap::new(),
         }
     }
 
+    ///
+    /// Sets the known failures baseline.
+    ///
+    pub fn with_baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
     ///
     /// Whether the test run has been successful.
     ///
+    /// The known failures from the baseline do not affect the result.
+    ///
     pub fn is_successful(&self) -> bool {
         for element in self.elements.iter() {
+            if element.is_expected_failure {
+                continue;
+            }
+
             match element.outcome {
                 Outcome::Passed { .. } => continue,
                 Outcome::Failed { .. } => return false,
+                Outcome::GasMismatch { .. } => return false,
+                Outcome::LimitExceeded { .. } => return false,
+                Outcome::Diverged { .. } => return false,
                 Outcome::Invalid { .. } => return false,
+                Outcome::Crashed { .. } => return false,
                 Outcome::Ignored => continue,
             }
         }
@@ -152,6 +193,39 @@ impl Summary {
         Ok(benchmark)
     }
 
+    ///
+    /// Returns the machine-readable report.
+    ///
+    pub fn report(&self) -> Report {
+        Report::new(self.elements.iter().map(Record::from).collect())
+    }
+
+    ///
+    /// Prints the known failures from the baseline which have fully passed.
+    ///
+    pub fn print_xpassed(&self) {
+        if self.quiet {
+            return;
+        }
+
+        for ((identifier, case, mode), _) in self
+            .expected_failures
+            .iter()
+            .filter(|(_, is_failed)| !**is_failed)
+        {
+            let name = match case {
+                Some(case) => format!("{identifier}::{case}"),
+                None => identifier.to_owned(),
+            };
+            println!(
+                "{:16} {:>7} {}",
+                mode.as_deref().unwrap_or_default().bright_white(),
+                "XPASS".bright_yellow(),
+                name,
+            );
+        }
+    }
+
     ///
     /// Wraps data into a thread-safe shared reference.
     ///
@@ -227,11 +301,69 @@ impl Summary {
         summary: Arc<Mutex<Self>>,
         mode: Mode,
         name: String,
+        group: Option<String>,
         expected: Output,
         found: Output,
         calldata: Vec<u8>,
     ) {
-        let element = Element::new(Some(mode), name, Outcome::failed(expected, found, calldata));
+        let element = Element::new(
+            Some(mode),
+            name,
+            Outcome::failed(group, expected, found, calldata),
+        );
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Adds a gas mismatch outcome.
+    ///
+    pub fn gas_mismatch(
+        summary: Arc<Mutex<Self>>,
+        mode: Mode,
+        name: String,
+        group: Option<String>,
+        expected: web3::types::U256,
+        found: u64,
+        calldata: Vec<u8>,
+    ) {
+        let element = Element::new(
+            Some(mode),
+            name,
+            Outcome::gas_mismatch(group, expected, found, calldata),
+        );
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Adds a failed outcome caused by exceeding an execution limit.
+    ///
+    pub fn limit_exceeded(
+        summary: Arc<Mutex<Self>>,
+        mode: Mode,
+        name: String,
+        group: Option<String>,
+        reason: String,
+        calldata: Vec<u8>,
+    ) {
+        let element = Element::new(
+            Some(mode),
+            name,
+            Outcome::limit_exceeded(group, reason, calldata),
+        );
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Adds a divergence outcome of the reference VM and vm2.
+    ///
+    pub fn diverged(
+        summary: Arc<Mutex<Self>>,
+        mode: Mode,
+        name: String,
+        group: Option<String>,
+        divergence: String,
+    ) {
+        let element = Element::new(Some(mode), name, Outcome::diverged(group, divergence));
         summary.lock().expect("Sync").push_element(element);
     }
 
@@ -246,6 +378,20 @@ impl Summary {
         summary.lock().expect("Sync").push_element(element);
     }
 
+    ///
+    /// Adds an element received from a worker process.
+    ///
+    pub fn forward(summary: Arc<Mutex<Self>>, element: Element) {
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Takes the elements collected so far, to be sent to the parent process.
+    ///
+    pub fn take_elements(summary: Arc<Mutex<Self>>) -> Vec<Element> {
+        summary.lock().expect("Sync").elements.drain(..).collect()
+    }
+
     ///
     /// Adds an ignored outcome.
     ///
@@ -271,44 +417,100 @@ impl Summary {
     ///
     /// Pushes an element to the summary, printing it.
     ///
-    fn push_element(&mut self, element: Element) {
-        if let Some(string) = element.print(self.verbosity) {
+    fn push_element(&mut self, mut element: Element) {
+        element.is_expected_failure = self
+            .baseline
+            .contains(element.name.as_str(), element.mode.as_ref());
+
+        if let Some(string) = element.print(self.verbosity).filter(|_| !self.quiet) {
             println!("{string}");
         }
 
         let is_executed = match element.outcome {
+            Outcome::Ignored => {
+                self.ignored += 1;
+                false
+            }
+            _ if element.is_expected_failure => {
+                let (identifier, case) = Rerun::split_name(element.name.as_str());
+                let is_failed = self
+                    .expected_failures
+                    .entry((
+                        identifier.to_owned(),
+                        case.map(|case| case.to_owned()),
+                        element.mode.as_ref().map(|mode| mode.to_string()),
+                    ))
+                    .or_default();
+                *is_failed |= !matches!(element.outcome, Outcome::Passed { .. });
+                true
+            }
             Outcome::Passed { .. } => {
                 self.passed += 1;
                 true
             }
-            Outcome::Failed { .. } => {
+            Outcome::Failed { .. } | Outcome::LimitExceeded { .. } => {
                 self.failed += 1;
                 true
             }
+            Outcome::GasMismatch { .. } => {
+                self.gas_mismatched += 1;
+                true
+            }
+            Outcome::Diverged { .. } => {
+                self.diverged += 1;
+                true
+            }
             Outcome::Invalid { .. } => {
                 self.invalid += 1;
                 true
             }
-            Outcome::Ignored => {
-                self.ignored += 1;
-                false
+            Outcome::Crashed { .. } => {
+                self.crashed += 1;
+                true
             }
         };
 
-        if is_executed {
+        if is_executed && !self.quiet {
             let milestone = if self.verbosity {
                 usize::pow(10, 3)
             } else {
                 usize::pow(10, 5)
             };
 
-            if (self.passed + self.failed + self.invalid) % milestone == 0 {
+            if (self.passed
+                + self.failed
+                + self.gas_mismatched
+                + self.diverged
+                + self.invalid
+                + self.crashed
+                + self.xfailed()
+                + self.xpassed())
+                % milestone
+                == 0
+            {
                 println!("{self}");
             }
         }
 
         self.elements.push(element);
     }
+
+    ///
+    /// Returns the number of the known failures which have failed in at least one element.
+    ///
+    fn xfailed(&self) -> usize {
+        self.expected_failures
+            .values()
+            .filter(|is_failed| **is_failed)
+            .count()
+    }
+
+    ///
+    /// Returns the number of the known failures which have passed in all their elements.
+    ///
+    fn xpassed(&self) -> usize {
+        self.expected_failures.len() - self.xfailed()
+    }
 }
 
 impl std::fmt::Display for Summary {
@@ -337,12 +539,52 @@ impl std::fmt::Display for Summary {
             "FAILED".bright_red(),
             self.failed.to_string().bright_red(),
         )?;
+        if self.gas_mismatched > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "GASDIFF".bright_red(),
+                self.gas_mismatched.to_string().bright_red(),
+            )?;
+        }
+        if self.diverged > 0 {
+            writeln!(
+                f,
+                "║     {:8}                                  {:10}     ║",
+                "DIVERGED".bright_red(),
+                self.diverged.to_string().bright_red(),
+            )?;
+        }
         writeln!(
             f,
             "║     {:7}                                   {:10}     ║",
             "INVALID".red(),
             self.invalid.to_string().red(),
         )?;
+        if self.crashed > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "CRASHED".bright_red(),
+                self.crashed.to_string().bright_red(),
+            )?;
+        }
+        if self.xfailed() > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "XFAIL".yellow(),
+                self.xfailed().to_string().yellow(),
+            )?;
+        }
+        if self.xpassed() > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "XPASS".bright_yellow(),
+                self.xpassed().to_string().bright_yellow(),
+            )?;
+        }
         writeln!(
             f,
             "║     {:7}                                   {:10}     ║",
@@ -352,7 +594,14 @@ impl std::fmt::Display for Summary {
         writeln!(
             f,
             "║               {:10} TESTS MILESTONE                     ║",
-            self.passed + self.failed + self.invalid,
+            self.passed
+                + self.failed
+                + self.gas_mismatched
+                + self.diverged
+                + self.invalid
+                + self.crashed
+                + self.xfailed()
+                + self.xpassed(),
         )?;
         writeln!(
             f,
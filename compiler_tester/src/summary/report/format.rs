@@ -0,0 +1,37 @@
+//!
+//! The compiler tester report format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The compiler tester report format.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The JUnit XML report.
+    JUnit,
+    /// The JSON report with one record per line.
+    JSONLines,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "junit" => Ok(Self::JUnit),
+            "json" => Ok(Self::JSONLines),
+            _ => Err("Could not parse report format. Supported formats: junit, json."),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::JUnit => write!(f, "junit"),
+            Self::JSONLines => write!(f, "json"),
+        }
+    }
+}
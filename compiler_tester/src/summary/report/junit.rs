@@ -0,0 +1,339 @@
+//!
+//! The compiler tester JUnit XML report.
+//!
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::record::Record;
+use super::record::RecordOutcome;
+
+/// The root test suite name.
+pub const TEST_SUITES_NAME: &str = "compiler-tester";
+
+///
+/// Serializes the records into a JUnit XML document.
+///
+/// The test cases are grouped into test suites by mode.
+///
+pub fn to_string(records: &[Record]) -> String {
+    let mut suites: BTreeMap<&str, Vec<&Record>> = BTreeMap::new();
+    for record in records.iter() {
+        suites
+            .entry(record.mode.as_deref().unwrap_or_default())
+            .or_default()
+            .push(record);
+    }
+
+    let mut xml = String::with_capacity(records.len() * 256);
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        xml,
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+        TEST_SUITES_NAME,
+        records.len(),
+        count(records.iter(), RecordOutcome::Failed)
+            + count(records.iter(), RecordOutcome::GasMismatch)
+            + count(records.iter(), RecordOutcome::Diverged),
+        count(records.iter(), RecordOutcome::Invalid)
+            + count(records.iter(), RecordOutcome::Crashed),
+        count(records.iter(), RecordOutcome::Ignored)
+            + records.iter().filter(|record| is_xfail(record)).count(),
+    )
+    .expect("Always valid");
+
+    for (mode, records) in suites.into_iter() {
+        writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\">",
+            escape(mode),
+            records.len(),
+            count(records.iter().copied(), RecordOutcome::Failed)
+                + count(records.iter().copied(), RecordOutcome::GasMismatch)
+                + count(records.iter().copied(), RecordOutcome::Diverged),
+            count(records.iter().copied(), RecordOutcome::Invalid)
+                + count(records.iter().copied(), RecordOutcome::Crashed),
+            count(records.iter().copied(), RecordOutcome::Ignored)
+                + records.iter().filter(|record| is_xfail(record)).count(),
+        )
+        .expect("Always valid");
+        for record in records.into_iter() {
+            write_test_case(&mut xml, record);
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+///
+/// Writes a single test case element.
+///
+fn write_test_case(xml: &mut String, record: &Record) {
+    writeln!(
+        xml,
+        "    <testcase name=\"{}\" classname=\"{}\">",
+        escape(record.name.as_str()),
+        escape(record.mode.as_deref().unwrap_or_default()),
+    )
+    .expect("Always valid");
+
+    let mut properties = Vec::with_capacity(6);
+    if let Some(ref group) = record.group {
+        properties.push(("group", group.to_owned()));
+    }
+    if let Some(size) = record.size {
+        properties.push(("size", size.to_string()));
+    }
+    if let Some(cycles) = record.cycles {
+        properties.push(("cycles", cycles.to_string()));
+    }
+    if let Some(ergs) = record.ergs {
+        properties.push(("ergs", ergs.to_string()));
+    }
+    if let Some(gas) = record.gas {
+        properties.push(("gas", gas.to_string()));
+    }
+    if record.is_expected_failure {
+        properties.push(("expected_failure", true.to_string()));
+    }
+    if !properties.is_empty() {
+        xml.push_str("      <properties>\n");
+        for (name, value) in properties.into_iter() {
+            writeln!(
+                xml,
+                "        <property name=\"{}\" value=\"{}\"/>",
+                name,
+                escape(value.as_str()),
+            )
+            .expect("Always valid");
+        }
+        xml.push_str("      </properties>\n");
+    }
+
+    match record.outcome {
+        _ if is_xfail(record) => xml.push_str("      <skipped message=\"Expected failure\"/>\n"),
+        RecordOutcome::Passed => {}
+        RecordOutcome::Failed if record.error.is_some() => {
+            writeln!(
+                xml,
+                "      <failure message=\"Limit exceeded\">{}\ncalldata {}</failure>",
+                escape(record.error.as_deref().unwrap_or_default()),
+                escape(record.calldata.as_deref().unwrap_or_default()),
+            )
+            .expect("Always valid");
+        }
+        RecordOutcome::Failed | RecordOutcome::GasMismatch => {
+            let message = match record.outcome {
+                RecordOutcome::GasMismatch => "Gas mismatch",
+                _ => "Output mismatch",
+            };
+            let expected = record
+                .expected
+                .as_ref()
+                .map(|expected| expected.to_string())
+                .unwrap_or_default();
+            let found = record
+                .found
+                .as_ref()
+                .map(|found| found.to_string())
+                .unwrap_or_default();
+            let calldata = record.calldata.as_deref().unwrap_or_default();
+            writeln!(
+                xml,
+                "      <failure message=\"{}\">expected {}\nfound {}\ncalldata {}</failure>",
+                message,
+                escape(expected.as_str()),
+                escape(found.as_str()),
+                escape(calldata),
+            )
+            .expect("Always valid");
+        }
+        RecordOutcome::Diverged => {
+            writeln!(
+                xml,
+                "      <failure message=\"VM divergence\">{}</failure>",
+                escape(record.error.as_deref().unwrap_or_default()),
+            )
+            .expect("Always valid");
+        }
+        RecordOutcome::Invalid => {
+            writeln!(
+                xml,
+                "      <error message=\"{}\"/>",
+                escape(record.error.as_deref().unwrap_or_default()),
+            )
+            .expect("Always valid");
+        }
+        RecordOutcome::Crashed => {
+            writeln!(
+                xml,
+                "      <error message=\"Worker crash\">{}</error>",
+                escape(record.error.as_deref().unwrap_or_default()),
+            )
+            .expect("Always valid");
+        }
+        RecordOutcome::Ignored => xml.push_str("      <skipped/>\n"),
+    }
+
+    xml.push_str("    </testcase>\n");
+}
+
+///
+/// Counts the records with the specified outcome.
+///
+fn count<'a, I>(records: I, outcome: RecordOutcome) -> usize
+where
+    I: Iterator<Item = &'a Record>,
+{
+    records
+        .filter(|record| record.outcome == outcome && !is_xfail(record))
+        .count()
+}
+
+///
+/// Whether the record is a known failure from the baseline, which is reported as skipped.
+///
+fn is_xfail(record: &Record) -> bool {
+    record.is_expected_failure
+        && !matches!(
+            record.outcome,
+            RecordOutcome::Passed | RecordOutcome::Ignored
+        )
+}
+
+///
+/// Escapes the XML special characters.
+///
+/// The characters forbidden in XML 1.0, e.g. the ANSI escapes from the worker error output,
+/// are replaced with the replacement character, as they cannot be escaped.
+///
+fn escape(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    for character in string.chars() {
+        match character {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\t' | '\n' | '\r' => result.push(character),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => {
+                result.push(char::REPLACEMENT_CHARACTER)
+            }
+            character => result.push(character),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::summary::element::outcome::passed_variant::PassedVariant;
+    use crate::summary::element::outcome::Outcome;
+    use crate::summary::element::Element;
+    use crate::summary::report::record::Record;
+    use crate::test::case::input::output::Output;
+
+    const NAME: &str = "tests/<a&b>.sol::'main'[\"x\"]";
+
+    fn records() -> Vec<Record> {
+        let mut expected_failure = Element::new(None, NAME.to_owned(), Outcome::invalid("error"));
+        expected_failure.is_expected_failure = true;
+
+        [
+            Element::new(
+                None,
+                NAME.to_owned(),
+                Outcome::passed(
+                    Some("group".to_owned()),
+                    PassedVariant::Runtime {
+                        cycles: 1,
+                        ergs: 2,
+                        gas: 3,
+                    },
+                ),
+            ),
+            Element::new(
+                None,
+                NAME.to_owned(),
+                Outcome::failed(None, Output::default(), Output::from(true), vec![0xaa]),
+            ),
+            Element::new(
+                None,
+                NAME.to_owned(),
+                Outcome::gas_mismatch(None, web3::types::U256::one(), 2, vec![]),
+            ),
+            Element::new(
+                None,
+                NAME.to_owned(),
+                Outcome::limit_exceeded(None, "limit".to_owned(), vec![]),
+            ),
+            Element::new(
+                None,
+                NAME.to_owned(),
+                Outcome::diverged(None, "divergence".to_owned()),
+            ),
+            Element::new(None, NAME.to_owned(), Outcome::invalid("error")),
+            Element::new(
+                None,
+                NAME.to_owned(),
+                Outcome::crashed("\u{1b}[31merror\u{1b}[0m\u{0}".to_owned()),
+            ),
+            Element::new(None, NAME.to_owned(), Outcome::ignored()),
+            expected_failure,
+        ]
+        .iter()
+        .map(Record::from)
+        .collect()
+    }
+
+    #[test]
+    fn outcomes() {
+        let xml = super::to_string(records().as_slice());
+
+        assert!(xml.contains(
+            "<testsuites name=\"compiler-tester\" tests=\"9\" failures=\"4\" errors=\"2\" skipped=\"2\">"
+        ));
+        assert_eq!(xml.matches("<testcase ").count(), 9);
+        assert_eq!(
+            xml.matches("<failure message=\"Output mismatch\">").count(),
+            1
+        );
+        assert_eq!(xml.matches("<failure message=\"Gas mismatch\">").count(), 1);
+        assert_eq!(
+            xml.matches("<failure message=\"Limit exceeded\">").count(),
+            1
+        );
+        assert_eq!(
+            xml.matches("<failure message=\"VM divergence\">").count(),
+            1
+        );
+        assert_eq!(xml.matches("<error message=\"error\"/>").count(), 1);
+        assert_eq!(xml.matches("<error message=\"Worker crash\">").count(), 1);
+        assert_eq!(xml.matches("<skipped/>").count(), 1);
+        assert_eq!(
+            xml.matches("<skipped message=\"Expected failure\"/>")
+                .count(),
+            1
+        );
+        assert_eq!(
+            xml.matches("<property name=\"group\" value=\"group\"/>")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        let xml = super::to_string(records().as_slice());
+
+        assert!(xml.contains("name=\"tests/&lt;a&amp;b&gt;.sol::&apos;main&apos;[&quot;x&quot;]\""));
+        assert!(!xml.contains(NAME));
+        assert!(xml.contains("\u{fffd}[31merror\u{fffd}[0m\u{fffd}"));
+        assert!(!xml
+            .chars()
+            .any(|character| character < ' ' && !matches!(character, '\t' | '\n' | '\r')));
+    }
+}
@@ -0,0 +1,124 @@
+//!
+//! The compiler tester machine-readable report.
+//!
+
+pub mod format;
+pub mod junit;
+pub mod record;
+
+use std::path::PathBuf;
+
+use self::format::Format;
+use self::record::Record;
+
+///
+/// The compiler tester machine-readable report.
+///
+#[derive(Debug, Default)]
+pub struct Report {
+    /// The report records, one per summary element.
+    pub records: Vec<Record>,
+}
+
+impl Report {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(records: Vec<Record>) -> Self {
+        Self { records }
+    }
+
+    ///
+    /// Writes the report to the file in the specified format.
+    ///
+    pub fn write_to_file(&self, path: PathBuf, format: Format) -> anyhow::Result<()> {
+        let contents = match format {
+            Format::JUnit => junit::to_string(self.records.as_slice()),
+            Format::JSONLines => self.to_json_lines(),
+        };
+        std::fs::write(path.as_path(), contents)
+            .map_err(|error| anyhow::anyhow!("Report file {:?} writing: {}", path, error))?;
+        Ok(())
+    }
+
+    ///
+    /// Serializes the records into JSON, one record per line.
+    ///
+    fn to_json_lines(&self) -> String {
+        let mut contents = String::with_capacity(self.records.len() * 256);
+        for record in self.records.iter() {
+            contents.push_str(
+                serde_json::to_string(record)
+                    .expect("Always valid")
+                    .as_str(),
+            );
+            contents.push('\n');
+        }
+        contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::summary::element::outcome::passed_variant::PassedVariant;
+    use crate::summary::element::outcome::Outcome;
+    use crate::summary::element::Element;
+    use crate::test::case::input::output::Output;
+
+    use super::record::Record;
+    use super::record::RecordOutcome;
+    use super::Report;
+
+    #[test]
+    fn json_lines() {
+        let name = "tests/<a&b>.sol::\"main\"\n[x]";
+        let outcomes = vec![
+            (
+                Outcome::passed(None, PassedVariant::Special),
+                RecordOutcome::Passed,
+            ),
+            (
+                Outcome::failed(None, Output::default(), Output::from(true), vec![0xaa]),
+                RecordOutcome::Failed,
+            ),
+            (
+                Outcome::gas_mismatch(None, web3::types::U256::one(), 2, vec![]),
+                RecordOutcome::GasMismatch,
+            ),
+            (
+                Outcome::limit_exceeded(None, "limit".to_owned(), vec![]),
+                RecordOutcome::Failed,
+            ),
+            (
+                Outcome::diverged(None, "divergence".to_owned()),
+                RecordOutcome::Diverged,
+            ),
+            (Outcome::invalid("error"), RecordOutcome::Invalid),
+            (
+                Outcome::crashed("\u{1b}[31merror\u{0}".to_owned()),
+                RecordOutcome::Crashed,
+            ),
+            (Outcome::ignored(), RecordOutcome::Ignored),
+        ];
+        let (elements, expected): (Vec<Element>, Vec<RecordOutcome>) = outcomes
+            .into_iter()
+            .map(|(outcome, expected)| (Element::new(None, name.to_owned(), outcome), expected))
+            .unzip();
+        let report = Report::new(elements.iter().map(Record::from).collect());
+
+        let contents = report.to_json_lines();
+        let records = contents
+            .lines()
+            .map(|line| serde_json::from_str::<Record>(line).expect("Always valid"))
+            .collect::<Vec<Record>>();
+
+        assert_eq!(contents.lines().count(), expected.len());
+        for (record, expected) in records.iter().zip(expected) {
+            assert_eq!(record.name, name);
+            assert_eq!(record.outcome, expected);
+        }
+        assert_eq!(records[1].calldata.as_deref(), Some("aa"));
+        assert_eq!(records[3].error.as_deref(), Some("limit"));
+        assert_eq!(records[6].error.as_deref(), Some("\u{1b}[31merror\u{0}"));
+    }
+}
@@ -0,0 +1,192 @@
+//!
+//! The compiler tester report record.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::summary::element::outcome::passed_variant::PassedVariant;
+use crate::summary::element::outcome::Outcome;
+use crate::summary::element::Element;
+
+///
+/// The compiler tester report record outcome.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordOutcome {
+    /// The `passed` outcome.
+    Passed,
+    /// The `failed` outcome.
+    Failed,
+    /// The `gas mismatch` outcome.
+    GasMismatch,
+    /// The `diverged` outcome.
+    Diverged,
+    /// The `invalid` outcome.
+    Invalid,
+    /// The `crashed` outcome.
+    Crashed,
+    /// The `ignored` outcome.
+    Ignored,
+}
+
+///
+/// The compiler tester report record.
+///
+/// Each record corresponds to a single summary element.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    /// The mode.
+    pub mode: Option<String>,
+    /// The test name.
+    pub name: String,
+    /// The test outcome.
+    pub outcome: RecordOutcome,
+    /// The test group name.
+    pub group: Option<String>,
+    /// The expected output, for failed tests.
+    pub expected: Option<serde_json::Value>,
+    /// The actual output, for failed tests.
+    pub found: Option<serde_json::Value>,
+    /// The hexadecimal calldata, for failed tests.
+    pub calldata: Option<String>,
+    /// The error description, for invalid, diverged, and limit exceeding tests.
+    pub error: Option<String>,
+    /// The contract size, for deploy calls.
+    pub size: Option<usize>,
+    /// The number of execution cycles.
+    pub cycles: Option<usize>,
+    /// The number of used ergs.
+    pub ergs: Option<u64>,
+    /// The number of used gas.
+    pub gas: Option<u64>,
+    /// Whether the test is listed in the known failures baseline.
+    #[serde(default)]
+    pub is_expected_failure: bool,
+}
+
+impl Record {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, mode: Option<String>, outcome: RecordOutcome) -> Self {
+        Self {
+            mode,
+            name,
+            outcome,
+            group: None,
+            expected: None,
+            found: None,
+            calldata: None,
+            error: None,
+            size: None,
+            cycles: None,
+            ergs: None,
+            gas: None,
+            is_expected_failure: false,
+        }
+    }
+}
+
+impl From<&Element> for Record {
+    fn from(element: &Element) -> Self {
+        let mode = element.mode.as_ref().map(|mode| mode.to_string());
+        let name = element.name.to_owned();
+
+        let mut record = match element.outcome {
+            Outcome::Passed {
+                ref variant,
+                ref group,
+            } => {
+                let mut record = Self::new(name, mode, RecordOutcome::Passed);
+                record.group = group.to_owned();
+                match variant {
+                    PassedVariant::Deploy {
+                        size,
+                        cycles,
+                        ergs,
+                        gas,
+                    } => {
+                        record.size = Some(*size);
+                        record.cycles = Some(*cycles);
+                        record.ergs = Some(*ergs);
+                        record.gas = Some(*gas);
+                    }
+                    PassedVariant::Runtime { cycles, ergs, gas } => {
+                        record.cycles = Some(*cycles);
+                        record.ergs = Some(*ergs);
+                        record.gas = Some(*gas);
+                    }
+                    PassedVariant::Special => {}
+                }
+                record
+            }
+            Outcome::Failed {
+                ref expected,
+                ref found,
+                ref calldata,
+                ref group,
+            } => {
+                let mut record = Self::new(name, mode, RecordOutcome::Failed);
+                record.group = group.to_owned();
+                record.expected = Some(serde_json::to_value(expected).expect("Always valid"));
+                record.found = Some(serde_json::to_value(found).expect("Always valid"));
+                record.calldata = Some(calldata.to_owned());
+                record
+            }
+            Outcome::GasMismatch {
+                expected,
+                found,
+                ref calldata,
+                ref group,
+            } => {
+                let mut record = Self::new(name, mode, RecordOutcome::GasMismatch);
+                record.group = group.to_owned();
+                record.expected = Some(if expected <= web3::types::U256::from(u64::MAX) {
+                    serde_json::Value::from(expected.as_u64())
+                } else {
+                    serde_json::Value::from(expected.to_string())
+                });
+                record.found = Some(serde_json::Value::from(found));
+                record.calldata = Some(calldata.to_owned());
+                record.gas = Some(found);
+                record
+            }
+            Outcome::LimitExceeded {
+                ref reason,
+                ref calldata,
+                ref group,
+            } => {
+                let mut record = Self::new(name, mode, RecordOutcome::Failed);
+                record.group = group.to_owned();
+                record.calldata = Some(calldata.to_owned());
+                record.error = Some(reason.to_owned());
+                record
+            }
+            Outcome::Diverged {
+                ref divergence,
+                ref group,
+            } => {
+                let mut record = Self::new(name, mode, RecordOutcome::Diverged);
+                record.group = group.to_owned();
+                record.error = Some(divergence.to_owned());
+                record
+            }
+            Outcome::Invalid { ref error } => {
+                let mut record = Self::new(name, mode, RecordOutcome::Invalid);
+                record.error = Some(error.to_owned());
+                record
+            }
+            Outcome::Crashed { ref reason } => {
+                let mut record = Self::new(name, mode, RecordOutcome::Crashed);
+                record.error = Some(reason.to_owned());
+                record
+            }
+            Outcome::Ignored => Self::new(name, mode, RecordOutcome::Ignored),
+        };
+        record.is_expected_failure = element.is_expected_failure;
+        record
+    }
+}
@@ -5,6 +5,7 @@
 use std::collections::BTreeMap;
 
 use crate::directories::matter_labs::test::metadata::case::input::calldata::Calldata as MatterLabsTestInputCalldata;
+use crate::test::case::input::typed_values::TypedValues;
 use crate::test::case::input::value::Value;
 use crate::test::instance::Instance;
 
@@ -49,6 +50,9 @@ impl Calldata {
                 }
                 result
             }
+            MatterLabsTestInputCalldata::Typed(typed_values) => {
+                TypedValues::try_from_matter_labs(typed_values, instances)?.encode()
+            }
         };
         Ok(Self { inner: calldata })
     }
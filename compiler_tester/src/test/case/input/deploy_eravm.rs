@@ -77,7 +77,7 @@ impl DeployEraVM {
         let name = format!("{}[#deployer:{}]", name_prefix, self.path);
 
         vm.populate_storage(self.storage.inner);
-        let result = match deployer.deploy_eravm::<M>(
+        let mut result = match deployer.deploy_eravm::<M>(
             name.clone(),
             self.caller,
             self.hash,
@@ -91,7 +91,16 @@ impl DeployEraVM {
                 return;
             }
         };
+        if let Some(divergence) = vm.take_divergence() {
+            Summary::diverged(summary, mode, name, test_group, divergence);
+            return;
+        }
+        if let Some(reason) = vm.take_limit_exceeded() {
+            Summary::limit_exceeded(summary, mode, name, test_group, reason, self.calldata.inner);
+            return;
+        }
 
+        result.output.storage = vm.read_storage_slots(self.expected.storage.as_slice());
         if result.output == self.expected {
             let build_size = match vm.get_contract_size(self.hash) {
                 Ok(size) => size,
@@ -115,6 +124,7 @@ impl DeployEraVM {
                 summary,
                 mode,
                 name,
+                test_group,
                 self.expected,
                 result.output,
                 self.calldata.inner,
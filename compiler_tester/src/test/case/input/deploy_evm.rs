@@ -33,6 +33,8 @@ pub struct DeployEVM {
     storage: Storage,
     /// The expected output.
     expected: Output,
+    /// The Ethereum test gas expectations.
+    gas_options: Vec<solidity_adapter::GasOption>,
 }
 
 impl DeployEVM {
@@ -47,6 +49,7 @@ impl DeployEVM {
         value: Option<u128>,
         storage: Storage,
         expected: Output,
+        gas_options: Vec<solidity_adapter::GasOption>,
     ) -> Self {
         Self {
             identifier,
@@ -56,6 +59,7 @@ impl DeployEVM {
             value,
             storage,
             expected,
+            gas_options,
         }
     }
 }
@@ -71,11 +75,14 @@ impl DeployEVM {
         mode: Mode,
         test_group: Option<String>,
         name_prefix: String,
+        check_gas: bool,
     ) {
         let name = format!("{}[#deployer:{}]", name_prefix, self.identifier);
 
+        let size = self.init_code.len();
+
         vm.populate_storage(self.storage.inner);
-        let result = match vm.execute_deploy_code(
+        let mut result = match vm.execute_deploy_code(
             name.clone(),
             self.identifier.as_str(),
             self.caller,
@@ -88,21 +95,48 @@ impl DeployEVM {
                 return;
             }
         };
+        if let Some(reason) = vm.take_limit_exceeded() {
+            Summary::limit_exceeded(summary, mode, name, test_group, reason, self.calldata.inner);
+            return;
+        }
+        let expected_gas = if check_gas {
+            mode.expected_gas(self.gas_options.as_slice())
+        } else {
+            None
+        };
+        result.output.storage = vm.read_storage_slots(self.expected.storage.as_slice());
         if result.output == self.expected {
-            Summary::passed_runtime(
-                summary,
-                mode,
-                name,
-                test_group,
-                result.cycles,
-                0,
-                result.gas,
-            );
+            match expected_gas {
+                Some(expected_gas) if expected_gas != web3::types::U256::from(result.gas) => {
+                    Summary::gas_mismatch(
+                        summary,
+                        mode,
+                        name,
+                        test_group,
+                        expected_gas,
+                        result.gas,
+                        self.calldata.inner,
+                    );
+                }
+                _ => {
+                    Summary::passed_deploy(
+                        summary,
+                        mode,
+                        name,
+                        test_group,
+                        size,
+                        result.cycles,
+                        result.ergs,
+                        result.gas,
+                    );
+                }
+            }
         } else {
             Summary::failed(
                 summary,
                 mode,
                 name,
+                test_group,
                 self.expected,
                 result.output,
                 self.calldata.inner,
@@ -129,7 +163,7 @@ impl DeployEVM {
         let size = self.init_code.len();
 
         vm.populate_storage(self.storage.inner);
-        let result = match deployer.deploy_evm::<M>(
+        let mut result = match deployer.deploy_evm::<M>(
             name.clone(),
             self.caller,
             self.init_code,
@@ -143,6 +177,15 @@ impl DeployEVM {
                 return;
             }
         };
+        if let Some(divergence) = vm.take_divergence() {
+            Summary::diverged(summary, mode, name, test_group, divergence);
+            return;
+        }
+        if let Some(reason) = vm.take_limit_exceeded() {
+            Summary::limit_exceeded(summary, mode, name, test_group, reason, self.calldata.inner);
+            return;
+        }
+        result.output.storage = vm.read_storage_slots(self.expected.storage.as_slice());
         if result.output == self.expected {
             Summary::passed_deploy(
                 summary,
@@ -159,6 +202,7 @@ impl DeployEVM {
                 summary,
                 mode,
                 name,
+                test_group,
                 self.expected,
                 result.output,
                 self.calldata.inner,
@@ -0,0 +1,63 @@
+//!
+//! The `isoltest_builtin_test` check input variant.
+//!
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::compilers::mode::Mode;
+use crate::summary::Summary;
+
+///
+/// The `isoltest_builtin_test` check input variant.
+///
+/// The builtin is evaluated by the tester itself and always returns `0x1234`, as in `isoltest`.
+///
+#[derive(Debug, Clone)]
+pub struct IsoltestBuiltinTest {
+    /// The expected return value.
+    expected: web3::types::U256,
+}
+
+impl IsoltestBuiltinTest {
+    /// The value returned by the builtin.
+    pub const RETURN_VALUE: u64 = 0x1234;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(expected: web3::types::U256) -> Self {
+        Self { expected }
+    }
+}
+
+impl IsoltestBuiltinTest {
+    ///
+    /// Evaluates the builtin and checks the result, which does not depend on the target.
+    ///
+    pub fn run(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        mode: Mode,
+        test_group: Option<String>,
+        name_prefix: String,
+        index: usize,
+    ) {
+        let name = format!("{name_prefix}[#isoltest_builtin_test:{index}]");
+
+        let found = web3::types::U256::from(Self::RETURN_VALUE);
+        if found == self.expected {
+            Summary::passed_special(summary, mode, name, test_group);
+        } else {
+            Summary::failed(
+                summary,
+                mode,
+                name,
+                test_group,
+                self.expected.into(),
+                found.into(),
+                vec![],
+            );
+        }
+    }
+}
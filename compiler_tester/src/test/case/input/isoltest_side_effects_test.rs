@@ -0,0 +1,79 @@
+//!
+//! The `isoltest_side_effects_test` check input variant.
+//!
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::compilers::mode::Mode;
+use crate::summary::Summary;
+use crate::test::case::input::output::Output;
+
+///
+/// The `isoltest_side_effects_test` check input variant.
+///
+/// The builtin is evaluated by the tester itself and returns its arguments as is, or a zero
+/// word if there are no arguments, as in `isoltest`.
+///
+/// The side effects expectations, that is, the `~` lines with the hexadecimal arguments, are not
+/// supported, and the tests specifying them are rejected by the Ethereum test parser.
+///
+#[derive(Debug, Clone)]
+pub struct IsoltestSideEffectsTest {
+    /// The raw arguments.
+    input: Vec<u8>,
+    /// The expected return values.
+    expected: Vec<web3::types::U256>,
+}
+
+impl IsoltestSideEffectsTest {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(input: Vec<u8>, expected: Vec<web3::types::U256>) -> Self {
+        Self { input, expected }
+    }
+}
+
+impl IsoltestSideEffectsTest {
+    ///
+    /// Evaluates the builtin and checks the result, which does not depend on the target.
+    ///
+    pub fn run(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        mode: Mode,
+        test_group: Option<String>,
+        name_prefix: String,
+        index: usize,
+    ) {
+        let name = format!("{name_prefix}[#isoltest_side_effects_test:{index}]");
+
+        let found: Vec<web3::types::U256> = if self.input.is_empty() {
+            vec![web3::types::U256::zero()]
+        } else {
+            self.input
+                .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+                .map(|chunk| {
+                    let mut word = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+                    word[..chunk.len()].copy_from_slice(chunk);
+                    web3::types::U256::from_big_endian(word.as_slice())
+                })
+                .collect()
+        };
+
+        if found == self.expected {
+            Summary::passed_special(summary, mode, name, test_group);
+        } else {
+            Summary::failed(
+                summary,
+                mode,
+                name,
+                test_group,
+                Output::from(self.expected),
+                Output::from(found),
+                self.input,
+            );
+        }
+    }
+}
@@ -6,10 +6,13 @@ pub mod balance;
 pub mod calldata;
 pub mod deploy_eravm;
 pub mod deploy_evm;
+pub mod isoltest_builtin_test;
+pub mod isoltest_side_effects_test;
 pub mod output;
 pub mod runtime;
 pub mod storage;
 pub mod storage_empty;
+pub mod typed_values;
 pub mod value;
 
 use std::collections::BTreeMap;
@@ -29,6 +32,8 @@ use self::balance::Balance;
 use self::calldata::Calldata;
 use self::deploy_eravm::DeployEraVM;
 use self::deploy_evm::DeployEVM;
+use self::isoltest_builtin_test::IsoltestBuiltinTest;
+use self::isoltest_side_effects_test::IsoltestSideEffectsTest;
 use self::output::Output;
 use self::runtime::Runtime;
 use self::storage::Storage;
@@ -49,6 +54,10 @@ pub enum Input {
     StorageEmpty(StorageEmpty),
     /// Check account balance.
     Balance(Balance),
+    /// The `isoltest_builtin_test` check.
+    IsoltestBuiltinTest(IsoltestBuiltinTest),
+    /// The `isoltest_side_effects_test` check.
+    IsoltestSideEffectsTest(IsoltestSideEffectsTest),
 }
 
 impl Input {
@@ -117,6 +126,7 @@ impl Input {
                     value,
                     storage,
                     expected,
+                    vec![],
                 )),
             },
             "#fallback" => {
@@ -135,6 +145,7 @@ impl Input {
                     value,
                     storage,
                     expected,
+                    vec![],
                 ))
             }
             entry => {
@@ -190,6 +201,7 @@ impl Input {
                     value,
                     storage,
                     expected,
+                    vec![],
                 ))
             }
         };
@@ -218,7 +230,7 @@ impl Input {
                 calldata,
                 value,
                 events,
-                ..
+                gas_options,
             } => {
                 let value = match value {
                     Some(value) => Some((*value).try_into().map_err(|error| {
@@ -254,6 +266,7 @@ impl Input {
                         value,
                         Storage::default(),
                         expected,
+                        gas_options.clone(),
                     ))),
                 }
             }
@@ -297,6 +310,7 @@ impl Input {
                         None,
                         Storage::default(),
                         expected,
+                        vec![],
                     ))),
                 }
             }
@@ -309,6 +323,14 @@ impl Input {
             solidity_adapter::FunctionCall::StorageEmpty { expected } => {
                 Some(Input::StorageEmpty(StorageEmpty::new(*expected)))
             }
+            solidity_adapter::FunctionCall::IsoltestBuiltinTest { expected } => Some(
+                Input::IsoltestBuiltinTest(IsoltestBuiltinTest::new(*expected)),
+            ),
+            solidity_adapter::FunctionCall::IsoltestSideEffectsTest { input, expected } => {
+                Some(Input::IsoltestSideEffectsTest(
+                    IsoltestSideEffectsTest::new(input.clone(), expected.clone()),
+                ))
+            }
             solidity_adapter::FunctionCall::Call {
                 method,
                 calldata,
@@ -316,7 +338,7 @@ impl Input {
                 expected,
                 failure,
                 events,
-                ..
+                gas_options,
             } => {
                 let value = match value {
                     Some(value) => Some((*value).try_into().map_err(|error| {
@@ -340,6 +362,7 @@ impl Input {
                     value,
                     Storage::default(),
                     expected,
+                    gas_options.clone(),
                 )))
             }
             _ => None,
@@ -384,6 +407,12 @@ impl Input {
             Self::Balance(balance_check) => {
                 balance_check.run_eravm(summary, vm, mode, test_group, name_prefix, index)
             }
+            Self::IsoltestBuiltinTest(builtin_test) => {
+                builtin_test.run(summary, mode, test_group, name_prefix, index)
+            }
+            Self::IsoltestSideEffectsTest(side_effects_test) => {
+                side_effects_test.run(summary, mode, test_group, name_prefix, index)
+            }
         };
     }
 
@@ -398,12 +427,15 @@ impl Input {
         test_group: Option<String>,
         name_prefix: String,
         index: usize,
+        check_gas: bool,
     ) {
         match self {
             Self::DeployEraVM { .. } => panic!("EraVM deploy transaction cannot be run on EVM"),
-            Self::DeployEVM(deploy) => deploy.run_evm(summary, vm, mode, test_group, name_prefix),
+            Self::DeployEVM(deploy) => {
+                deploy.run_evm(summary, vm, mode, test_group, name_prefix, check_gas)
+            }
             Self::Runtime(runtime) => {
-                runtime.run_evm(summary, vm, mode, test_group, name_prefix, index)
+                runtime.run_evm(summary, vm, mode, test_group, name_prefix, index, check_gas)
             }
             Self::StorageEmpty(storage_empty) => {
                 storage_empty.run_evm(summary, vm, mode, test_group, name_prefix, index)
@@ -411,6 +443,12 @@ impl Input {
             Self::Balance(balance_check) => {
                 balance_check.run_evm(summary, vm, mode, test_group, name_prefix, index)
             }
+            Self::IsoltestBuiltinTest(builtin_test) => {
+                builtin_test.run(summary, mode, test_group, name_prefix, index)
+            }
+            Self::IsoltestSideEffectsTest(side_effects_test) => {
+                side_effects_test.run(summary, mode, test_group, name_prefix, index)
+            }
         };
     }
 
@@ -450,6 +488,12 @@ impl Input {
             Self::Balance(balance_check) => {
                 balance_check.run_evm_interpreter(summary, vm, mode, test_group, name_prefix, index)
             }
+            Self::IsoltestBuiltinTest(builtin_test) => {
+                builtin_test.run(summary, mode, test_group, name_prefix, index)
+            }
+            Self::IsoltestSideEffectsTest(side_effects_test) => {
+                side_effects_test.run(summary, mode, test_group, name_prefix, index)
+            }
         };
     }
 }
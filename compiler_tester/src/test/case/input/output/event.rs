@@ -5,6 +5,7 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use serde::Deserialize;
 use serde::Serialize;
 
 use crate::directories::matter_labs::test::metadata::case::input::expected::variant::extended::event::Event as MatterLabsTestExpectedEvent;
@@ -14,7 +15,7 @@ use crate::test::case::input::value::Value;
 ///
 /// The compiler test outcome event.
 ///
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Event {
     /// The event address.
     address: Option<web3::types::Address>,
@@ -0,0 +1,36 @@
+//!
+//! The compiler test outcome exception kind.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The compiler test outcome exception kind.
+///
+/// The kinds are reported per target, as the VMs do not distinguish the same failures.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExceptionKind {
+    /// The clean revert, possibly with revert data.
+    Revert,
+    /// The panic, e.g. an invalid opcode or jump.
+    /// On EraVM, running out of ergs is also a panic, since every panic burns the remaining ergs.
+    Panic,
+    /// The exhaustion of gas on EVM, or the exhaustion of cycles on EraVM.
+    OutOfGas,
+    /// The stack or call depth limit exceeded.
+    DepthLimit,
+}
+
+impl std::fmt::Display for ExceptionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Revert => write!(f, "revert"),
+            Self::Panic => write!(f, "panic"),
+            Self::OutOfGas => write!(f, "out_of_gas"),
+            Self::DepthLimit => write!(f, "depth_limit"),
+        }
+    }
+}
@@ -3,32 +3,51 @@
 //!
 
 pub mod event;
+pub mod exception_kind;
+pub mod revert;
+pub mod storage_slot;
 
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use serde::Deserialize;
 use serde::Serialize;
 
 use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::case::input::expected::variant::extended::return_data::ReturnData as MatterLabsTestExpectedReturnData;
 use crate::directories::matter_labs::test::metadata::case::input::expected::variant::Variant as MatterLabsTestExpectedVariant;
 use crate::directories::matter_labs::test::metadata::case::input::expected::Expected as MatterLabsTestExpected;
+use crate::test::case::input::typed_values::TypedValues;
 use crate::test::case::input::value::Value;
 use crate::test::instance::Instance;
 use crate::vm::evm::output::Output as EVMOutput;
 
 use self::event::Event;
+use self::exception_kind::ExceptionKind;
+use self::revert::Revert;
+use self::storage_slot::StorageSlot;
 
 ///
 /// The compiler test outcome data.
 ///
-#[derive(Debug, Default, Serialize, Clone)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct Output {
     /// The return data values.
     pub return_data: Vec<Value>,
     /// Whether an exception is thrown,
     pub exception: bool,
+    /// The exception kind, if known or expected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exception_kind: Option<ExceptionKind>,
     /// The emitted events.
     pub events: Vec<Event>,
+    /// The contracts storage slots after the run.
+    /// Only the expected slots are checked, so the actual values are read for the same keys.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage: Vec<StorageSlot>,
+    /// The custom error signatures used to decode the revert data.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_errors: Vec<String>,
 }
 
 impl Output {
@@ -39,7 +58,10 @@ impl Output {
         Self {
             return_data,
             exception,
+            exception_kind: None,
             events,
+            storage: vec![],
+            custom_errors: vec![],
         }
     }
 
@@ -71,11 +93,19 @@ impl Output {
             })
             .ok_or_else(|| anyhow::anyhow!("Version not covered"))?;
 
-        let (return_data, exception, events) = match variant {
-            MatterLabsTestExpectedVariant::Simple(return_data) => (return_data, false, Vec::new()),
+        let (return_data, exception, mut exception_kind, events, storage, revert) = match variant {
+            MatterLabsTestExpectedVariant::Simple(return_data) => (
+                MatterLabsTestExpectedReturnData::List(return_data),
+                false,
+                None,
+                Vec::new(),
+                Vec::new(),
+                (None, None, None),
+            ),
             MatterLabsTestExpectedVariant::Extended(expected) => {
+                let exception = expected.is_exception();
+                let exception_kind = expected.exception_kind;
                 let return_data = expected.return_data;
-                let exception = expected.exception;
                 let events = expected
                     .events
                     .into_iter()
@@ -87,16 +117,87 @@ impl Output {
                     })
                     .collect::<anyhow::Result<Vec<Event>>>()
                     .map_err(|error| anyhow::anyhow!("Invalid events: {}", error))?;
-                (return_data, exception, events)
+                let storage = StorageSlot::try_from_matter_labs(expected.storage, instances)
+                    .map_err(|error| anyhow::anyhow!("Invalid storage: {}", error))?;
+                let revert = (expected.revert, expected.panic, expected.custom_error);
+                (
+                    return_data,
+                    exception,
+                    exception_kind,
+                    events,
+                    storage,
+                    revert,
+                )
+            }
+        };
+        let mut return_data = match return_data {
+            MatterLabsTestExpectedReturnData::List(return_data) => {
+                Value::try_from_vec_matter_labs(return_data, instances)
+            }
+            MatterLabsTestExpectedReturnData::Typed(typed_values) => {
+                TypedValues::try_from_matter_labs(typed_values, instances)
+                    .map(|typed_values| typed_values.encode_words())
+            }
+        }
+        .map_err(|error| anyhow::anyhow!("Invalid return data: {error}"))?;
+
+        let mut custom_errors = Vec::new();
+        let revert = match revert {
+            (None, None, None) => None,
+            (Some(message), None, None) => Some(Revert::Error(message)),
+            (None, Some(code), None) => {
+                let code = match code {
+                    serde_json::Value::String(code) => code,
+                    code => code.to_string(),
+                };
+                match Value::try_from_matter_labs(code, instances)
+                    .map_err(|error| anyhow::anyhow!("Invalid panic code: {error}"))?
+                {
+                    Value::Certain(code) => Some(Revert::Panic(code)),
+                    Value::Any => anyhow::bail!("Panic code can not be `*`"),
+                }
             }
+            (None, None, Some(signature)) => {
+                let mut arguments =
+                    Vec::with_capacity(return_data.len() * era_compiler_common::BYTE_LENGTH_FIELD);
+                for value in return_data.drain(..) {
+                    let value = match value {
+                        Value::Certain(value) => value,
+                        Value::Any => {
+                            anyhow::bail!(
+                                "The `*` wildcard is not allowed in custom error arguments"
+                            )
+                        }
+                    };
+                    let mut bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+                    value.to_big_endian(&mut bytes);
+                    arguments.extend(bytes);
+                }
+                custom_errors.push(signature.clone());
+                Some(Revert::try_custom_error(signature, arguments.as_slice())?)
+            }
+            _ => anyhow::bail!("Only one of `revert`, `panic`, and `custom_error` can be expected"),
         };
-        let return_data = Value::try_from_vec_matter_labs(return_data, instances)
-            .map_err(|error| anyhow::anyhow!("Invalid return data: {error}"))?;
+        if let Some(revert) = revert {
+            if !return_data.is_empty() {
+                anyhow::bail!("The return data must be empty if `revert` or `panic` is expected");
+            }
+            return_data = revert.encode_words();
+            match exception_kind {
+                None | Some(ExceptionKind::Revert) => exception_kind = Some(ExceptionKind::Revert),
+                Some(exception_kind) => anyhow::bail!(
+                    "The revert data can only be expected with the `revert` exception kind, found `{exception_kind}`"
+                ),
+            }
+        }
 
         Ok(Self {
             return_data,
             exception,
+            exception_kind,
             events,
+            storage,
+            custom_errors,
         })
     }
 
@@ -132,7 +233,10 @@ impl Output {
         Self {
             return_data,
             exception,
+            exception_kind: None,
             events,
+            storage: vec![],
+            custom_errors: vec![],
         }
     }
 }
@@ -142,7 +246,23 @@ impl From<web3::types::U256> for Output {
         Self {
             return_data: vec![Value::Certain(value)],
             exception: false,
+            exception_kind: None,
+            events: vec![],
+            storage: vec![],
+            custom_errors: vec![],
+        }
+    }
+}
+
+impl From<Vec<web3::types::U256>> for Output {
+    fn from(values: Vec<web3::types::U256>) -> Self {
+        Self {
+            return_data: values.into_iter().map(Value::Certain).collect(),
+            exception: false,
+            exception_kind: None,
             events: vec![],
+            storage: vec![],
+            custom_errors: vec![],
         }
     }
 }
@@ -197,7 +317,10 @@ impl From<zkevm_tester::runners::compiler_tests::VmSnapshot> for Output {
                 Self {
                     return_data,
                     exception: false,
+                    exception_kind: None,
                     events,
+                    storage: vec![],
+                    custom_errors: vec![],
                 }
             }
             zkevm_tester::runners::compiler_tests::VmExecutionResult::Revert(return_data) => {
@@ -222,20 +345,29 @@ impl From<zkevm_tester::runners::compiler_tests::VmSnapshot> for Output {
                 Self {
                     return_data,
                     exception: true,
+                    exception_kind: Some(ExceptionKind::Revert),
                     events,
+                    storage: vec![],
+                    custom_errors: vec![],
                 }
             }
             zkevm_tester::runners::compiler_tests::VmExecutionResult::Panic => Self {
                 return_data: vec![],
                 exception: true,
+                exception_kind: Some(ExceptionKind::Panic),
                 events,
+                storage: vec![],
+                custom_errors: vec![],
             },
             zkevm_tester::runners::compiler_tests::VmExecutionResult::MostLikelyDidNotFinish {
                 ..
             } => Self {
                 return_data: vec![],
                 exception: true,
+                exception_kind: Some(ExceptionKind::OutOfGas),
                 events,
+                storage: vec![],
+                custom_errors: vec![],
             },
         }
     }
@@ -266,7 +398,10 @@ impl From<EVMOutput> for Output {
         Self {
             return_data,
             exception: output.exception,
+            exception_kind: output.exception_kind,
             events,
+            storage: vec![],
+            custom_errors: vec![],
         }
     }
 }
@@ -276,6 +411,10 @@ impl PartialEq<Self> for Output {
         if self.exception != other.exception {
             return false;
         }
+        // The actual output is compared as `actual == expected`, so the kind is checked if expected.
+        if other.exception_kind.is_some() && self.exception_kind != other.exception_kind {
+            return false;
+        }
         if self.events.len() != other.events.len() {
             return false;
         }
@@ -299,6 +438,10 @@ impl PartialEq<Self> for Output {
             }
         }
 
+        if self.storage != other.storage {
+            return false;
+        }
+
         true
     }
 }
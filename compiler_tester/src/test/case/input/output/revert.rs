@@ -0,0 +1,274 @@
+//!
+//! The compiler test outcome revert reason.
+//!
+
+use web3::ethabi::ParamType;
+use web3::ethabi::Token;
+
+use crate::test::case::input::value::Value;
+
+///
+/// The compiler test outcome revert reason.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Revert {
+    /// The `Error(string)` revert, e.g. `require(false, "message")`.
+    Error(String),
+    /// The `Panic(uint256)` revert, e.g. `0x11` for an arithmetic overflow.
+    Panic(web3::types::U256),
+    /// The custom error revert.
+    CustomError {
+        /// The error signature, e.g. `Err(uint256)`.
+        signature: String,
+        /// The error arguments.
+        arguments: Vec<Token>,
+    },
+}
+
+impl Revert {
+    /// The `Error(string)` signature.
+    pub const ERROR_SIGNATURE: &'static str = "Error(string)";
+
+    /// The `Panic(uint256)` signature.
+    pub const PANIC_SIGNATURE: &'static str = "Panic(uint256)";
+
+    ///
+    /// Creates a custom error revert from the ABI-encoded arguments.
+    ///
+    pub fn try_custom_error(signature: String, arguments: &[u8]) -> anyhow::Result<Self> {
+        let types = Self::parameter_types(signature.as_str())?;
+        let arguments = web3::ethabi::decode(types.as_slice(), arguments).map_err(|error| {
+            anyhow::anyhow!("Custom error `{signature}` arguments decoding: {error}")
+        })?;
+        Ok(Self::CustomError {
+            signature,
+            arguments,
+        })
+    }
+
+    ///
+    /// Returns the ABI-encoded revert data, that is, the selector followed by the arguments.
+    ///
+    pub fn encode(&self) -> Vec<u8> {
+        let (signature, arguments) = match self {
+            Self::Error(message) => (Self::ERROR_SIGNATURE, vec![Token::String(message.clone())]),
+            Self::Panic(code) => (Self::PANIC_SIGNATURE, vec![Token::Uint(*code)]),
+            Self::CustomError {
+                signature,
+                arguments,
+            } => (signature.as_str(), arguments.clone()),
+        };
+        let mut data = crate::utils::selector(signature).to_vec();
+        data.extend(web3::ethabi::encode(arguments.as_slice()));
+        data
+    }
+
+    ///
+    /// Returns the ABI-encoded revert data split into 32-byte words, where the last word is
+    /// right-padded with zeros, as the return data is represented in the test output.
+    ///
+    pub fn encode_words(&self) -> Vec<Value> {
+        self.encode()
+            .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+            .map(|word| {
+                let mut word_padded = word.to_vec();
+                word_padded.resize(era_compiler_common::BYTE_LENGTH_FIELD, 0);
+                Value::Certain(web3::types::U256::from_big_endian(word_padded.as_slice()))
+            })
+            .collect()
+    }
+
+    ///
+    /// Decodes the revert data from the return data words.
+    ///
+    /// Besides `Error(string)` and `Panic(uint256)`, the custom errors with the specified
+    /// signatures are recognized. Returns `None` if the data is not a known revert.
+    ///
+    pub fn decode(return_data: &[Value], custom_errors: &[String]) -> Option<Self> {
+        let mut data =
+            Vec::with_capacity(return_data.len() * era_compiler_common::BYTE_LENGTH_FIELD);
+        for value in return_data.iter() {
+            let value = match value {
+                Value::Certain(value) => value,
+                Value::Any => return None,
+            };
+            let mut bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+            value.to_big_endian(&mut bytes);
+            data.extend(bytes);
+        }
+        if data.len() < 4 {
+            return None;
+        }
+        let (selector, arguments) = data.split_at(4);
+
+        if selector == crate::utils::selector(Self::ERROR_SIGNATURE) {
+            return match web3::ethabi::decode(&[ParamType::String], arguments)
+                .ok()?
+                .pop()?
+            {
+                Token::String(message) => Some(Self::Error(message)),
+                _ => None,
+            };
+        }
+        if selector == crate::utils::selector(Self::PANIC_SIGNATURE) {
+            return match web3::ethabi::decode(&[ParamType::Uint(256)], arguments)
+                .ok()?
+                .pop()?
+            {
+                Token::Uint(code) => Some(Self::Panic(code)),
+                _ => None,
+            };
+        }
+        custom_errors
+            .iter()
+            .find(|signature| selector == crate::utils::selector(signature.as_str()))
+            .and_then(|signature| Self::try_custom_error(signature.to_owned(), arguments).ok())
+    }
+
+    ///
+    /// Returns the parameter types of the error signature, e.g. `Err(uint256,string)`.
+    ///
+    fn parameter_types(signature: &str) -> anyhow::Result<Vec<ParamType>> {
+        let parameters = signature
+            .find('(')
+            .map(|index| &signature[index..])
+            .filter(|parameters| parameters.ends_with(')'))
+            .ok_or_else(|| anyhow::anyhow!("Invalid custom error signature `{signature}`"))?;
+        if parameters == "()" {
+            return Ok(vec![]);
+        }
+        match web3::ethabi::param_type::Reader::read(parameters) {
+            Ok(ParamType::Tuple(types)) => Ok(types),
+            Ok(_) => anyhow::bail!("Invalid custom error signature `{signature}`"),
+            Err(error) => anyhow::bail!("Invalid custom error signature `{signature}`: {error}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Revert {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error(message) => write!(f, "Error({message:?})"),
+            Self::Panic(code) => write!(f, "Panic(0x{code:x})"),
+            Self::CustomError {
+                signature,
+                arguments,
+            } => {
+                let name = signature
+                    .split_once('(')
+                    .map(|(name, _)| name)
+                    .unwrap_or(signature.as_str());
+                write!(
+                    f,
+                    "{name}({})",
+                    arguments
+                        .iter()
+                        .map(|argument| argument.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use web3::ethabi::ParamType;
+    use web3::ethabi::Token;
+
+    use crate::test::case::input::value::Value;
+
+    use super::Revert;
+
+    fn round_trip(revert: Revert, custom_errors: &[String]) {
+        let words = revert.encode_words();
+        assert_eq!(
+            Revert::decode(words.as_slice(), custom_errors),
+            Some(revert)
+        );
+    }
+
+    #[test]
+    fn error() {
+        let revert = Revert::Error("message".to_owned());
+        assert_eq!(&revert.encode()[..4], &[0x08, 0xc3, 0x79, 0xa0]);
+        round_trip(revert, &[]);
+    }
+
+    #[test]
+    fn panic() {
+        let revert = Revert::Panic(web3::types::U256::from(0x11));
+        assert_eq!(&revert.encode()[..4], &[0x4e, 0x48, 0x7b, 0x71]);
+        round_trip(revert, &[]);
+    }
+
+    #[test]
+    fn custom_error() {
+        let signature = "Err(uint256,string)".to_owned();
+        let arguments = web3::ethabi::encode(&[
+            Token::Uint(web3::types::U256::from(42)),
+            Token::String("message".to_owned()),
+        ]);
+        let revert = Revert::try_custom_error(signature.clone(), arguments.as_slice())
+            .expect("Always valid");
+        assert_eq!(
+            revert,
+            Revert::CustomError {
+                signature: signature.clone(),
+                arguments: vec![
+                    Token::Uint(web3::types::U256::from(42)),
+                    Token::String("message".to_owned()),
+                ],
+            }
+        );
+        assert_eq!(revert.to_string(), "Err(2a, message)");
+
+        round_trip(revert.clone(), &[signature]);
+        assert_eq!(Revert::decode(revert.encode_words().as_slice(), &[]), None);
+    }
+
+    #[test]
+    fn custom_error_without_arguments() {
+        let signature = "Unauthorized()".to_owned();
+        let revert = Revert::try_custom_error(signature.clone(), &[]).expect("Always valid");
+        assert_eq!(revert.encode().len(), 4);
+        round_trip(revert, &[signature]);
+    }
+
+    #[test]
+    fn parameter_types() {
+        assert_eq!(
+            Revert::parameter_types("Err(uint256,string,address[])").expect("Always valid"),
+            vec![
+                ParamType::Uint(256),
+                ParamType::String,
+                ParamType::Array(Box::new(ParamType::Address)),
+            ]
+        );
+        assert_eq!(
+            Revert::parameter_types("Err()").expect("Always valid"),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn error_malformed_signature() {
+        for signature in ["Err", "Err(uint256", "Err(unknown)", "Err uint256)"] {
+            assert!(
+                Revert::try_custom_error(signature.to_owned(), &[]).is_err(),
+                "{signature}"
+            );
+        }
+    }
+
+    #[test]
+    fn decode_unknown() {
+        assert_eq!(Revert::decode(&[], &[]), None);
+        assert_eq!(
+            Revert::decode(&[Value::Certain(web3::types::U256::one())], &[]),
+            None
+        );
+        assert_eq!(Revert::decode(&[Value::Any], &[]), None);
+    }
+}
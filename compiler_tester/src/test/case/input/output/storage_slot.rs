@@ -0,0 +1,79 @@
+//!
+//! The compiler test outcome storage slot.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::directories::matter_labs::test::metadata::case::input::storage::Storage as MatterLabsTestContractStorage;
+use crate::test::case::input::storage::Storage;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The compiler test outcome storage slot.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StorageSlot {
+    /// The contract address.
+    pub address: web3::types::Address,
+    /// The storage key.
+    pub key: web3::types::U256,
+    /// The storage value.
+    pub value: Value,
+}
+
+impl StorageSlot {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: web3::types::Address, key: web3::types::U256, value: Value) -> Self {
+        Self {
+            address,
+            key,
+            value,
+        }
+    }
+
+    ///
+    /// Try convert from Matter Labs compiler test metadata expected storage.
+    ///
+    /// The slots are sorted by address and key.
+    ///
+    pub fn try_from_matter_labs(
+        storage: HashMap<String, MatterLabsTestContractStorage>,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<Vec<Self>> {
+        let mut slots = Vec::new();
+        for (address, contract_storage) in storage.into_iter() {
+            let address = Storage::try_address_from_matter_labs(address.as_str(), instances)?;
+            for (key, value) in contract_storage.into_entries().into_iter() {
+                let key = Storage::try_key_from_matter_labs(key, instances)?;
+                let value = Value::try_from_matter_labs(value, instances)
+                    .map_err(|error| anyhow::anyhow!("Invalid storage value: {}", error))?;
+                slots.push(Self::new(address, key, value));
+            }
+        }
+        slots.sort_by_key(|slot| (slot.address, slot.key));
+        Ok(slots)
+    }
+}
+
+impl PartialEq<Self> for StorageSlot {
+    fn eq(&self, other: &Self) -> bool {
+        if self.address != other.address || self.key != other.key {
+            return false;
+        }
+
+        if let (Value::Certain(value1), Value::Certain(value2)) = (&self.value, &other.value) {
+            if value1 != value2 {
+                return false;
+            }
+        }
+
+        true
+    }
+}
@@ -34,6 +34,8 @@ pub struct Runtime {
     storage: Storage,
     /// The expected output.
     expected: Output,
+    /// The Ethereum test gas expectations.
+    gas_options: Vec<solidity_adapter::GasOption>,
 }
 
 impl Runtime {
@@ -48,6 +50,7 @@ impl Runtime {
         value: Option<u128>,
         storage: Storage,
         expected: Output,
+        gas_options: Vec<solidity_adapter::GasOption>,
     ) -> Self {
         Self {
             name,
@@ -57,6 +60,7 @@ impl Runtime {
             value,
             storage,
             expected,
+            gas_options,
         }
     }
 }
@@ -90,6 +94,14 @@ impl Runtime {
                 return;
             }
         };
+        if let Some(divergence) = vm.take_divergence() {
+            Summary::diverged(summary, mode, name, test_group, divergence);
+            return;
+        }
+        if let Some(reason) = vm.take_limit_exceeded() {
+            Summary::limit_exceeded(summary, mode, name, test_group, reason, self.calldata.inner);
+            return;
+        }
         let gas = if let Some(benchmark_analyzer::Benchmark::EVM_INTERPRETER_GROUP_NAME) =
             test_group.as_deref()
         {
@@ -113,6 +125,7 @@ impl Runtime {
             0
         };
 
+        result.output.storage = vm.read_storage_slots(self.expected.storage.as_slice());
         if result.output == self.expected {
             Summary::passed_runtime(
                 summary,
@@ -128,6 +141,7 @@ impl Runtime {
                 summary,
                 mode,
                 name,
+                test_group,
                 self.expected,
                 result.output,
                 self.calldata.inner,
@@ -146,10 +160,11 @@ impl Runtime {
         test_group: Option<String>,
         name_prefix: String,
         index: usize,
+        check_gas: bool,
     ) {
         let name = format!("{}[{}:{}]", name_prefix, self.name, index);
         vm.populate_storage(self.storage.inner);
-        let result = match vm.execute_runtime_code(
+        let mut result = match vm.execute_runtime_code(
             name.clone(),
             self.address,
             self.caller,
@@ -162,21 +177,47 @@ impl Runtime {
                 return;
             }
         };
+        if let Some(reason) = vm.take_limit_exceeded() {
+            Summary::limit_exceeded(summary, mode, name, test_group, reason, self.calldata.inner);
+            return;
+        }
+        let expected_gas = if check_gas {
+            mode.expected_gas(self.gas_options.as_slice())
+        } else {
+            None
+        };
+        result.output.storage = vm.read_storage_slots(self.expected.storage.as_slice());
         if result.output == self.expected {
-            Summary::passed_runtime(
-                summary,
-                mode,
-                name,
-                test_group,
-                result.cycles,
-                result.ergs,
-                result.gas,
-            );
+            match expected_gas {
+                Some(expected_gas) if expected_gas != web3::types::U256::from(result.gas) => {
+                    Summary::gas_mismatch(
+                        summary,
+                        mode,
+                        name,
+                        test_group,
+                        expected_gas,
+                        result.gas,
+                        self.calldata.inner,
+                    );
+                }
+                _ => {
+                    Summary::passed_runtime(
+                        summary,
+                        mode,
+                        name,
+                        test_group,
+                        result.cycles,
+                        result.ergs,
+                        result.gas,
+                    );
+                }
+            }
         } else {
             Summary::failed(
                 summary,
                 mode,
                 name,
+                test_group,
                 self.expected,
                 result.output,
                 self.calldata.inner,
@@ -226,6 +267,14 @@ impl Runtime {
                 return;
             }
         };
+        if let Some(divergence) = vm.take_divergence() {
+            Summary::diverged(summary, mode, name, test_group, divergence);
+            return;
+        }
+        if let Some(reason) = vm.take_limit_exceeded() {
+            Summary::limit_exceeded(summary, mode, name, test_group, reason, calldata);
+            return;
+        }
         if result.output.return_data.is_empty() {
             Summary::invalid(
                 summary,
@@ -242,6 +291,7 @@ impl Runtime {
             .unwrap_certain_as_ref()
             .as_u64();
 
+        result.output.storage = vm.read_storage_slots(self.expected.storage.as_slice());
         if result.output == self.expected {
             Summary::passed_runtime(
                 summary,
@@ -253,7 +303,15 @@ impl Runtime {
                 gas,
             );
         } else {
-            Summary::failed(summary, mode, name, self.expected, result.output, calldata);
+            Summary::failed(
+                summary,
+                mode,
+                name,
+                test_group,
+                self.expected,
+                result.output,
+                calldata,
+            );
         }
     }
 }
@@ -30,36 +30,9 @@ impl Storage {
         let mut result = HashMap::new();
 
         for (address, contract_storage) in storage.into_iter() {
-            let address = if let Some(instance) = address.strip_suffix(".address") {
-                instances
-                    .get(instance)
-                    .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", instance))?
-                    .address()
-                    .copied()
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("Instance `{}` is not successfully deployed", instance)
-                    })
-            } else {
-                web3::types::Address::from_str(address.as_str())
-                    .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
-            }
-            .map_err(|error| anyhow::anyhow!("Invalid storage address: {}", error))?;
-
-            let contract_storage = match contract_storage {
-                MatterLabsTestContractStorage::List(list) => list
-                    .into_iter()
-                    .enumerate()
-                    .map(|(key, value)| (key.to_string(), value))
-                    .collect(),
-                MatterLabsTestContractStorage::Map(map) => map.clone(),
-            };
-            for (key, value) in contract_storage.into_iter() {
-                let key = match Value::try_from_matter_labs(key, instances)
-                    .map_err(|error| anyhow::anyhow!("Invalid storage key: {}", error))?
-                {
-                    Value::Certain(value) => value,
-                    Value::Any => anyhow::bail!("Storage key can not be `*`"),
-                };
+            let address = Self::try_address_from_matter_labs(address.as_str(), instances)?;
+            for (key, value) in contract_storage.into_entries().into_iter() {
+                let key = Self::try_key_from_matter_labs(key, instances)?;
 
                 let value = match Value::try_from_matter_labs(value, instances)
                     .map_err(|error| anyhow::anyhow!("Invalid storage value: {}", error))?
@@ -78,4 +51,43 @@ impl Storage {
 
         Ok(Self { inner: result })
     }
+
+    ///
+    /// Try convert the Matter Labs compiler test storage address, which is either a literal or
+    /// an `<instance>.address` reference.
+    ///
+    pub fn try_address_from_matter_labs(
+        address: &str,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<web3::types::Address> {
+        if let Some(instance) = address.strip_suffix(".address") {
+            instances
+                .get(instance)
+                .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", instance))?
+                .address()
+                .copied()
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Instance `{}` is not successfully deployed", instance)
+                })
+        } else {
+            web3::types::Address::from_str(address)
+                .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
+        }
+        .map_err(|error| anyhow::anyhow!("Invalid storage address: {}", error))
+    }
+
+    ///
+    /// Try convert the Matter Labs compiler test storage key.
+    ///
+    pub fn try_key_from_matter_labs(
+        key: String,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<web3::types::U256> {
+        match Value::try_from_matter_labs(key, instances)
+            .map_err(|error| anyhow::anyhow!("Invalid storage key: {}", error))?
+        {
+            Value::Certain(value) => Ok(value),
+            Value::Any => anyhow::bail!("Storage key can not be `*`"),
+        }
+    }
 }
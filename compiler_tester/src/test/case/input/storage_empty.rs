@@ -51,6 +51,7 @@ impl StorageEmpty {
                 summary,
                 mode,
                 name,
+                test_group,
                 self.is_empty.into(),
                 found.into(),
                 vec![],
@@ -63,14 +64,29 @@ impl StorageEmpty {
     ///
     pub fn run_evm(
         self,
-        _summary: Arc<Mutex<Summary>>,
-        _vm: &EVM,
-        _mode: Mode,
-        _test_group: Option<String>,
-        _name_prefix: String,
-        _index: usize,
+        summary: Arc<Mutex<Summary>>,
+        vm: &EVM,
+        mode: Mode,
+        test_group: Option<String>,
+        name_prefix: String,
+        index: usize,
     ) {
-        todo!()
+        let name = format!("{name_prefix}[#storage_empty_check:{index}]");
+
+        let found = vm.is_storage_empty();
+        if found == self.is_empty {
+            Summary::passed_special(summary, mode, name, test_group);
+        } else {
+            Summary::failed(
+                summary,
+                mode,
+                name,
+                test_group,
+                self.is_empty.into(),
+                found.into(),
+                vec![],
+            );
+        }
     }
 
     ///
@@ -78,13 +94,28 @@ impl StorageEmpty {
     ///
     pub fn run_evm_interpreter(
         self,
-        _summary: Arc<Mutex<Summary>>,
-        _vm: &EraVM,
-        _mode: Mode,
-        _test_group: Option<String>,
-        _name_prefix: String,
-        _index: usize,
+        summary: Arc<Mutex<Summary>>,
+        vm: &EraVM,
+        mode: Mode,
+        test_group: Option<String>,
+        name_prefix: String,
+        index: usize,
     ) {
-        todo!()
+        let name = format!("{name_prefix}[#storage_empty_check:{index}]");
+
+        let found = vm.is_storage_empty();
+        if found == self.is_empty {
+            Summary::passed_special(summary, mode, name, test_group);
+        } else {
+            Summary::failed(
+                summary,
+                mode,
+                name,
+                test_group,
+                self.is_empty.into(),
+                found.into(),
+                vec![],
+            );
+        }
     }
 }
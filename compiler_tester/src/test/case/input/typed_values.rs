@@ -0,0 +1,341 @@
+//!
+//! The test input ABI-typed values.
+//!
+
+use std::collections::BTreeMap;
+
+use web3::ethabi::ParamType;
+use web3::ethabi::Token;
+
+use crate::directories::matter_labs::test::metadata::case::input::typed_values::TypedValues as MatterLabsTestTypedValues;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The test input ABI-typed values.
+///
+#[derive(Debug, Clone)]
+pub struct TypedValues {
+    /// The ABI tokens.
+    pub tokens: Vec<Token>,
+}
+
+impl TypedValues {
+    ///
+    /// Try convert from Matter Labs compiler test metadata typed values.
+    ///
+    pub fn try_from_matter_labs(
+        typed_values: MatterLabsTestTypedValues,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<Self> {
+        if typed_values.types.len() != typed_values.values.len() {
+            anyhow::bail!(
+                "Expected {} values according to the types, found {}",
+                typed_values.types.len(),
+                typed_values.values.len()
+            );
+        }
+
+        let tokens = typed_values
+            .types
+            .iter()
+            .zip(typed_values.values.iter())
+            .enumerate()
+            .map(|(index, (r#type, value))| {
+                let r#type = web3::ethabi::param_type::Reader::read(r#type.as_str())
+                    .map_err(|error| anyhow::anyhow!("Invalid type `{}`: {}", r#type, error))?;
+                Self::tokenize(&r#type, value, instances)
+                    .map_err(|error| anyhow::anyhow!("Value #{index} is invalid: {error}"))
+            })
+            .collect::<anyhow::Result<Vec<Token>>>()?;
+
+        Ok(Self { tokens })
+    }
+
+    ///
+    /// Returns the ABI-encoded values.
+    ///
+    pub fn encode(&self) -> Vec<u8> {
+        web3::ethabi::encode(self.tokens.as_slice())
+    }
+
+    ///
+    /// Returns the ABI-encoded values split into 32-byte words.
+    ///
+    pub fn encode_words(&self) -> Vec<Value> {
+        self.encode()
+            .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+            .map(|word| Value::Certain(web3::types::U256::from_big_endian(word)))
+            .collect()
+    }
+
+    ///
+    /// Converts the JSON value to the ABI token of the specified type.
+    ///
+    fn tokenize(
+        r#type: &ParamType,
+        value: &serde_json::Value,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<Token> {
+        let token = match (r#type, value) {
+            (ParamType::Address, serde_json::Value::String(value)) => {
+                let value = Self::parse_integer(value.as_str(), instances)?;
+                let mut bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+                value.to_big_endian(&mut bytes);
+                Token::Address(web3::types::Address::from_slice(
+                    &bytes[era_compiler_common::BYTE_LENGTH_FIELD
+                        - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..],
+                ))
+            }
+            (ParamType::Uint(_), serde_json::Value::String(value)) => {
+                Token::Uint(Self::parse_unsigned_integer(value.as_str(), instances)?)
+            }
+            (ParamType::Uint(_), serde_json::Value::Number(value)) => Token::Uint(
+                Self::parse_unsigned_integer(value.to_string().as_str(), instances)?,
+            ),
+            (ParamType::Int(_), serde_json::Value::String(value)) => {
+                Token::Int(Self::parse_integer(value.as_str(), instances)?)
+            }
+            (ParamType::Int(_), serde_json::Value::Number(value)) => {
+                Token::Int(Self::parse_integer(value.to_string().as_str(), instances)?)
+            }
+            (ParamType::Bool, serde_json::Value::Bool(value)) => Token::Bool(*value),
+            (ParamType::String, serde_json::Value::String(value)) => Token::String(value.clone()),
+            (ParamType::Bytes, serde_json::Value::String(value)) => {
+                Token::Bytes(Self::parse_bytes(value.as_str())?)
+            }
+            (ParamType::FixedBytes(size), serde_json::Value::String(value)) => {
+                let mut bytes = Self::parse_bytes(value.as_str())?;
+                if bytes.len() > *size {
+                    anyhow::bail!("Expected at most {size} bytes, found {}", bytes.len());
+                }
+                bytes.resize(*size, 0);
+                Token::FixedBytes(bytes)
+            }
+            (ParamType::Array(r#type), serde_json::Value::Array(values)) => Token::Array(
+                values
+                    .iter()
+                    .map(|value| Self::tokenize(r#type, value, instances))
+                    .collect::<anyhow::Result<Vec<Token>>>()?,
+            ),
+            (ParamType::FixedArray(r#type, size), serde_json::Value::Array(values)) => {
+                if values.len() != *size {
+                    anyhow::bail!("Expected {size} array elements, found {}", values.len());
+                }
+                Token::FixedArray(
+                    values
+                        .iter()
+                        .map(|value| Self::tokenize(r#type, value, instances))
+                        .collect::<anyhow::Result<Vec<Token>>>()?,
+                )
+            }
+            (ParamType::Tuple(types), serde_json::Value::Array(values)) => {
+                if values.len() != types.len() {
+                    anyhow::bail!(
+                        "Expected {} tuple elements, found {}",
+                        types.len(),
+                        values.len()
+                    );
+                }
+                Token::Tuple(
+                    types
+                        .iter()
+                        .zip(values.iter())
+                        .map(|(r#type, value)| Self::tokenize(r#type, value, instances))
+                        .collect::<anyhow::Result<Vec<Token>>>()?,
+                )
+            }
+            (r#type, value) => {
+                anyhow::bail!("Value `{}` does not match the type `{}`", value, r#type)
+            }
+        };
+        Ok(token)
+    }
+
+    ///
+    /// Parses an integer in the Matter Labs value format, including negative numbers and instance addresses.
+    ///
+    fn parse_integer(
+        value: &str,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<web3::types::U256> {
+        match Value::try_from_matter_labs(value.to_owned(), instances)? {
+            Value::Certain(value) => Ok(value),
+            Value::Any => anyhow::bail!("The `*` wildcard is not allowed in typed values"),
+        }
+    }
+
+    ///
+    /// Parses an integer in the Matter Labs value format, rejecting negative numbers.
+    ///
+    fn parse_unsigned_integer(
+        value: &str,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<web3::types::U256> {
+        if value.starts_with('-') {
+            anyhow::bail!("Negative value `{value}` is not allowed for unsigned integer types");
+        }
+        Self::parse_integer(value, instances)
+    }
+
+    ///
+    /// Parses a hexadecimal byte string starting with `0x`.
+    ///
+    fn parse_bytes(value: &str) -> anyhow::Result<Vec<u8>> {
+        let hex = value.strip_prefix("0x").ok_or_else(|| {
+            anyhow::anyhow!("Expected a hexadecimal starting with `0x`, found `{value}`")
+        })?;
+        hex::decode(hex)
+            .map_err(|error| anyhow::anyhow!("Hexadecimal value `{value}` decoding error: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use web3::ethabi::Token;
+
+    use super::TypedValues;
+    use crate::directories::matter_labs::test::metadata::case::input::typed_values::TypedValues as MatterLabsTestTypedValues;
+    use crate::test::instance::Instance;
+
+    fn typed_values(
+        types: &[&str],
+        values: serde_json::Value,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<TypedValues> {
+        let values = match values {
+            serde_json::Value::Array(values) => values,
+            _ => panic!("The values must be an array"),
+        };
+        TypedValues::try_from_matter_labs(
+            MatterLabsTestTypedValues {
+                types: types.iter().map(|r#type| r#type.to_string()).collect(),
+                values,
+            },
+            instances,
+        )
+    }
+
+    #[test]
+    fn string() {
+        let result = typed_values(
+            &["string"],
+            serde_json::json!(["Hello, World!"]),
+            &BTreeMap::new(),
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            result.tokens,
+            vec![Token::String("Hello, World!".to_owned())]
+        );
+        assert_eq!(result.encode(), web3::ethabi::encode(&result.tokens));
+        assert_eq!(result.encode_words().len(), 3);
+    }
+
+    #[test]
+    fn bytes() {
+        let result = typed_values(
+            &["bytes", "bytes4"],
+            serde_json::json!(["0x010203", "0xff"]),
+            &BTreeMap::new(),
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            result.tokens,
+            vec![
+                Token::Bytes(vec![0x01, 0x02, 0x03]),
+                Token::FixedBytes(vec![0xff, 0x00, 0x00, 0x00]),
+            ]
+        );
+    }
+
+    #[test]
+    fn tuple_array() {
+        let address = web3::types::Address::from_low_u64_be(0x1234);
+        let mut instances = BTreeMap::new();
+        instances.insert(
+            "Test".to_owned(),
+            Instance::evm("Test".to_owned(), Some(address), true, false, vec![]),
+        );
+
+        let result = typed_values(
+            &["(uint256,address)[]"],
+            serde_json::json!([[["1", "Test.address"], [2, "0x10"]]]),
+            &instances,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            result.tokens,
+            vec![Token::Array(vec![
+                Token::Tuple(vec![
+                    Token::Uint(web3::types::U256::one()),
+                    Token::Address(address),
+                ]),
+                Token::Tuple(vec![
+                    Token::Uint(web3::types::U256::from(2)),
+                    Token::Address(web3::types::Address::from_low_u64_be(0x10)),
+                ]),
+            ])]
+        );
+    }
+
+    #[test]
+    fn negative_int() {
+        let result = typed_values(
+            &["int256", "int8"],
+            serde_json::json!(["-1", -2]),
+            &BTreeMap::new(),
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            result.tokens,
+            vec![
+                Token::Int(web3::types::U256::max_value()),
+                Token::Int(web3::types::U256::max_value() - web3::types::U256::one()),
+            ]
+        );
+    }
+
+    #[test]
+    fn error_negative_uint() {
+        assert!(typed_values(&["uint256"], serde_json::json!(["-1"]), &BTreeMap::new()).is_err());
+        assert!(typed_values(&["uint8"], serde_json::json!([-1]), &BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn error_count_mismatch() {
+        assert!(typed_values(
+            &["uint256", "bool"],
+            serde_json::json!(["1"]),
+            &BTreeMap::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn error_type_mismatch() {
+        assert!(typed_values(&["bool"], serde_json::json!(["true"]), &BTreeMap::new()).is_err());
+        assert!(typed_values(
+            &["uint256[2]"],
+            serde_json::json!([["1"]]),
+            &BTreeMap::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn error_unknown_instance() {
+        assert!(typed_values(
+            &["address"],
+            serde_json::json!(["Unknown.address"]),
+            &BTreeMap::new()
+        )
+        .is_err());
+    }
+}
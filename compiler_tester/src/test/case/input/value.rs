@@ -5,6 +5,8 @@
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 use serde::Serializer;
 
@@ -111,3 +113,21 @@ impl Serialize for Value {
         serializer.serialize_str(&value_str)
     }
 }
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value_str = String::deserialize(deserializer)?;
+        if value_str == "*" {
+            return Ok(Value::Any);
+        }
+        let value = value_str
+            .strip_prefix("0x")
+            .ok_or_else(|| serde::de::Error::custom("Expected a hexadecimal value or `*`"))?;
+        web3::types::U256::from_str(value)
+            .map(Value::Certain)
+            .map_err(serde::de::Error::custom)
+    }
+}
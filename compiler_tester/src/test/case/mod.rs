@@ -7,14 +7,17 @@ pub mod input;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Instant;
 
 use crate::compilers::mode::Mode;
 use crate::directories::matter_labs::test::metadata::case::Case as MatterLabsTestCase;
 use crate::summary::Summary;
 use crate::test::instance::Instance;
+use crate::vm::chain_context::ChainContext;
 use crate::vm::eravm::deployers::EraVMDeployer;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::EVM;
+use crate::vm::limits::Limits;
 
 use self::input::Input;
 
@@ -27,14 +30,28 @@ pub struct Case {
     name: Option<String>,
     /// The case inputs.
     inputs: Vec<Input>,
+    /// The case execution limits overriding the default ones.
+    limits: Limits,
+    /// The chain contexts of the inputs, with the input overrides applied over the case ones.
+    chain_contexts: Vec<ChainContext>,
 }
 
 impl Case {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(name: Option<String>, inputs: Vec<Input>) -> Self {
-        Self { name, inputs }
+    pub fn new(
+        name: Option<String>,
+        inputs: Vec<Input>,
+        limits: Limits,
+        chain_contexts: Vec<ChainContext>,
+    ) -> Self {
+        Self {
+            name,
+            inputs,
+            limits,
+            chain_contexts,
+        }
     }
 
     ///
@@ -47,14 +64,17 @@ impl Case {
         method_identifiers: &Option<BTreeMap<String, BTreeMap<String, u32>>>,
     ) -> anyhow::Result<Self> {
         let mut inputs = Vec::with_capacity(case.inputs.len());
+        let mut chain_contexts = Vec::with_capacity(case.inputs.len());
 
         for (index, input) in case.inputs.into_iter().enumerate() {
+            chain_contexts.push(input.chain_context.or(case.chain_context));
             let input = Input::try_from_matter_labs(input, mode, instances, method_identifiers)
                 .map_err(|error| anyhow::anyhow!("Input #{} is invalid: {}", index, error))?;
             inputs.push(input);
         }
 
-        Ok(Self::new(Some(case.name), inputs))
+        let limits = Limits::new(case.cycles, case.ergs, case.gas, None);
+        Ok(Self::new(Some(case.name), inputs, limits, chain_contexts))
     }
 
     ///
@@ -85,7 +105,8 @@ impl Case {
             }
         }
 
-        Ok(Self::new(None, inputs))
+        let chain_contexts = vec![ChainContext::default(); inputs.len()];
+        Ok(Self::new(None, inputs, Limits::default(), chain_contexts))
     }
 
     ///
@@ -98,6 +119,7 @@ impl Case {
         mode: &Mode,
         test_name: String,
         test_group: Option<String>,
+        deadline: Option<Instant>,
     ) where
         D: EraVMDeployer,
     {
@@ -107,7 +129,14 @@ impl Case {
             test_name
         };
 
-        for (index, input) in self.inputs.into_iter().enumerate() {
+        vm.set_limits(self.limits.or(Limits::get()));
+        for (index, (input, chain_context)) in
+            self.inputs.into_iter().zip(self.chain_contexts).enumerate()
+        {
+            if Self::is_timed_out(summary.clone(), mode, &name, &test_group, deadline) {
+                return;
+            }
+            vm.set_chain_context(chain_context);
             input.run_eravm::<_, M>(
                 summary.clone(),
                 &mut vm,
@@ -130,6 +159,8 @@ impl Case {
         mode: &Mode,
         test_name: String,
         test_group: Option<String>,
+        check_gas: bool,
+        deadline: Option<Instant>,
     ) {
         let name = if let Some(case_name) = self.name {
             format!("{test_name}::{case_name}")
@@ -137,7 +168,14 @@ impl Case {
             test_name
         };
 
-        for (index, input) in self.inputs.into_iter().enumerate() {
+        vm.set_gas_limit(self.limits.or(Limits::get()).gas);
+        for (index, (input, chain_context)) in
+            self.inputs.into_iter().zip(self.chain_contexts).enumerate()
+        {
+            if Self::is_timed_out(summary.clone(), mode, &name, &test_group, deadline) {
+                return;
+            }
+            vm.set_chain_context(chain_context);
             input.run_evm(
                 summary.clone(),
                 &mut vm,
@@ -145,6 +183,7 @@ impl Case {
                 test_group.clone(),
                 name.clone(),
                 index,
+                check_gas,
             )
         }
     }
@@ -159,6 +198,7 @@ impl Case {
         mode: &Mode,
         test_name: String,
         test_group: Option<String>,
+        deadline: Option<Instant>,
     ) where
         D: EraVMDeployer,
     {
@@ -168,7 +208,14 @@ impl Case {
             test_name
         };
 
-        for (index, input) in self.inputs.into_iter().enumerate() {
+        vm.set_limits(self.limits.or(Limits::get()));
+        for (index, (input, chain_context)) in
+            self.inputs.into_iter().zip(self.chain_contexts).enumerate()
+        {
+            if Self::is_timed_out(summary.clone(), mode, &name, &test_group, deadline) {
+                return;
+            }
+            vm.set_chain_context(chain_context);
             input.run_evm_interpreter::<_, M>(
                 summary.clone(),
                 &mut vm,
@@ -180,4 +227,31 @@ impl Case {
             )
         }
     }
+
+    ///
+    /// Checks whether the test deadline has passed, reporting the rest of the case as failed.
+    ///
+    fn is_timed_out(
+        summary: Arc<Mutex<Summary>>,
+        mode: &Mode,
+        name: &str,
+        test_group: &Option<String>,
+        deadline: Option<Instant>,
+    ) -> bool {
+        match deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                let timeout = Limits::get().timeout.unwrap_or_default();
+                Summary::limit_exceeded(
+                    summary,
+                    mode.to_owned(),
+                    name.to_owned(),
+                    test_group.to_owned(),
+                    format!("the timeout of {}s has been exceeded", timeout.as_secs()),
+                    vec![],
+                );
+                true
+            }
+            _ => false,
+        }
+    }
 }
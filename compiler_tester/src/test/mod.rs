@@ -8,6 +8,7 @@ pub mod instance;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Instant;
 
 use crate::compilers::mode::Mode;
 use crate::summary::Summary;
@@ -16,8 +17,9 @@ use crate::vm::eravm::deployers::EraVMDeployer;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::input::build::Build as EVMBuild;
 use crate::vm::evm::invoker::Invoker as EVMInvoker;
-use crate::vm::evm::runtime::Runtime as EVMRuntime;
+use crate::vm::evm::invoker::PrecompileSet as EVMPrecompileSet;
 use crate::vm::evm::EVM;
+use crate::vm::limits::Limits;
 
 ///
 /// The test.
@@ -67,6 +69,7 @@ impl Test {
     where
         D: EraVMDeployer,
     {
+        let deadline = Self::deadline();
         for case in self.cases {
             let vm = EraVM::clone_with_contracts(vm.clone(), self.eravm_builds.clone());
             case.run_eravm::<D, M>(
@@ -75,6 +78,7 @@ impl Test {
                 &self.mode,
                 self.name.clone(),
                 self.group.clone(),
+                deadline,
             );
         }
     }
@@ -82,13 +86,14 @@ impl Test {
     ///
     /// Runs the test on EVM.
     ///
-    pub fn run_evm(self, summary: Arc<Mutex<Summary>>) {
+    pub fn run_evm(self, summary: Arc<Mutex<Summary>>, check_gas: bool) {
+        let deadline = Self::deadline();
         for case in self.cases {
+            // Keep in sync with the EVM version the `--check-gas` expectations are limited to.
             let config = evm::standard::Config::shanghai();
-            let etable =
-                evm::Etable::<evm::standard::State, EVMRuntime, evm::trap::CallCreateTrap>::runtime(
-                );
-            let resolver = evm::standard::EtableResolver::new(&config, &(), &etable);
+            let etable = crate::vm::evm::invoker::etable();
+            let precompiles = EVMPrecompileSet::new(&config);
+            let resolver = evm::standard::EtableResolver::new(&config, &precompiles, &etable);
             let invoker = EVMInvoker::new(&config, &resolver);
 
             let vm = EVM::new(self.evm_builds.clone(), invoker);
@@ -98,6 +103,8 @@ impl Test {
                 &self.mode,
                 self.name.clone(),
                 self.group.clone(),
+                check_gas,
+                deadline,
             );
         }
     }
@@ -109,6 +116,7 @@ impl Test {
     where
         D: EraVMDeployer,
     {
+        let deadline = Self::deadline();
         for case in self.cases {
             let vm = EraVM::clone_with_contracts(vm.clone(), self.eravm_builds.clone());
             case.run_evm_interpreter::<D, M>(
@@ -117,7 +125,17 @@ impl Test {
                 &self.mode,
                 self.name.clone(),
                 self.group.clone(),
+                deadline,
             );
         }
     }
+
+    ///
+    /// Returns the wall-clock deadline of the test, if the timeout is set.
+    ///
+    fn deadline() -> Option<Instant> {
+        Limits::get()
+            .timeout
+            .map(|timeout| Instant::now() + timeout)
+    }
 }
@@ -0,0 +1,110 @@
+//!
+//! The VM chain context.
+//!
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+///
+/// The VM chain context.
+///
+/// The unset values are taken from the defaults shared by all backends. It can be overridden by
+/// each test case and input.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct ChainContext {
+    /// The chain ID.
+    pub chain_id: Option<u64>,
+    /// The transaction origin, `0x`-prefixed.
+    /// If unset, EraVM uses its default origin, and EVM uses the transaction caller.
+    pub origin: Option<web3::types::Address>,
+    /// The current block number.
+    pub block_number: Option<u64>,
+    /// The current block timestamp.
+    pub block_timestamp: Option<u64>,
+    /// The current block coinbase, `0x`-prefixed.
+    pub coinbase: Option<web3::types::Address>,
+    /// The current block base fee.
+    pub base_fee: Option<u64>,
+}
+
+impl ChainContext {
+    /// The default chain ID.
+    pub const DEFAULT_CHAIN_ID: u64 = 280;
+
+    /// The default current block number.
+    pub const DEFAULT_BLOCK_NUMBER: u64 = 300;
+
+    /// The default current block timestamp.
+    pub const DEFAULT_BLOCK_TIMESTAMP: u64 = 0xdeadbeef;
+
+    /// The default current block coinbase.
+    pub const DEFAULT_COINBASE: u64 = 0x8001;
+
+    /// The default current block base fee.
+    pub const DEFAULT_BASE_FEE: u64 = 7;
+
+    /// The hash of the zero block. The hash of each next block is incremented by one.
+    pub const ZERO_BLOCK_HASH: &'static str =
+        "0x3737373737373737373737373737373737373737373737373737373737373737";
+
+    ///
+    /// Returns the context with the unset values taken from `defaults`.
+    ///
+    pub fn or(self, defaults: Self) -> Self {
+        Self {
+            chain_id: self.chain_id.or(defaults.chain_id),
+            origin: self.origin.or(defaults.origin),
+            block_number: self.block_number.or(defaults.block_number),
+            block_timestamp: self.block_timestamp.or(defaults.block_timestamp),
+            coinbase: self.coinbase.or(defaults.coinbase),
+            base_fee: self.base_fee.or(defaults.base_fee),
+        }
+    }
+
+    ///
+    /// Returns the chain ID.
+    ///
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id.unwrap_or(Self::DEFAULT_CHAIN_ID)
+    }
+
+    ///
+    /// Returns the current block number.
+    ///
+    pub fn block_number(&self) -> u64 {
+        self.block_number.unwrap_or(Self::DEFAULT_BLOCK_NUMBER)
+    }
+
+    ///
+    /// Returns the current block timestamp.
+    ///
+    pub fn block_timestamp(&self) -> u64 {
+        self.block_timestamp
+            .unwrap_or(Self::DEFAULT_BLOCK_TIMESTAMP)
+    }
+
+    ///
+    /// Returns the current block coinbase.
+    ///
+    pub fn coinbase(&self) -> web3::types::Address {
+        self.coinbase
+            .unwrap_or_else(|| web3::types::Address::from_low_u64_be(Self::DEFAULT_COINBASE))
+    }
+
+    ///
+    /// Returns the current block base fee.
+    ///
+    pub fn base_fee(&self) -> u64 {
+        self.base_fee.unwrap_or(Self::DEFAULT_BASE_FEE)
+    }
+
+    ///
+    /// Returns the hash of the block with the specified number.
+    ///
+    pub fn block_hash(number: u64) -> web3::types::U256 {
+        web3::types::U256::from_str(Self::ZERO_BLOCK_HASH).expect("Always valid")
+            + web3::types::U256::from(number)
+    }
+}
@@ -0,0 +1,79 @@
+//!
+//! The differential comparison of the reference EraVM and vm2.
+//!
+
+use std::collections::HashMap;
+
+use crate::test::case::input::output::Output;
+use crate::vm::execution_result::ExecutionResult;
+
+///
+/// Compares the results and storage states produced by the reference EraVM and vm2
+/// from the same initial snapshot.
+///
+/// Returns the divergence description, if any.
+///
+pub fn compare(
+    reference: &ExecutionResult,
+    reference_storage: &HashMap<
+        zkevm_tester::runners::compiler_tests::StorageKey,
+        web3::types::H256,
+    >,
+    candidate: &ExecutionResult,
+    candidate_storage: &HashMap<
+        zkevm_tester::runners::compiler_tests::StorageKey,
+        web3::types::H256,
+    >,
+) -> Option<String> {
+    let mut differences = Vec::new();
+
+    if reference.output.exception != candidate.output.exception {
+        differences.push(format!(
+            "exception: reference {}, vm2 {}",
+            reference.output.exception, candidate.output.exception,
+        ));
+    }
+
+    let reference_return_data = Output::new(reference.output.return_data.clone(), false, vec![]);
+    let candidate_return_data = Output::new(candidate.output.return_data.clone(), false, vec![]);
+    if reference_return_data != candidate_return_data {
+        differences.push(format!(
+            "return data: reference {}, vm2 {}",
+            serde_json::to_string(&reference_return_data.return_data).expect("Always valid"),
+            serde_json::to_string(&candidate_return_data.return_data).expect("Always valid"),
+        ));
+    }
+
+    let reference_events = Output::new(vec![], false, reference.output.events.clone());
+    let candidate_events = Output::new(vec![], false, candidate.output.events.clone());
+    if reference_events != candidate_events {
+        differences.push(format!(
+            "events: reference {}, vm2 {}",
+            serde_json::to_string(&reference_events.events).expect("Always valid"),
+            serde_json::to_string(&candidate_events.events).expect("Always valid"),
+        ));
+    }
+
+    let mut storage_keys = reference_storage
+        .keys()
+        .chain(candidate_storage.keys())
+        .collect::<Vec<_>>();
+    storage_keys.sort_by_key(|key| (key.address, key.key));
+    storage_keys.dedup();
+    for key in storage_keys.into_iter() {
+        let reference_value = reference_storage.get(key).copied().unwrap_or_default();
+        let candidate_value = candidate_storage.get(key).copied().unwrap_or_default();
+        if reference_value != candidate_value {
+            differences.push(format!(
+                "storage {:?}[{:#x}]: reference {:?}, vm2 {:?}",
+                key.address, key.key, reference_value, candidate_value,
+            ));
+        }
+    }
+
+    if differences.is_empty() {
+        None
+    } else {
+        Some(differences.join("; "))
+    }
+}
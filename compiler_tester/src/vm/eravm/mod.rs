@@ -8,6 +8,8 @@ pub mod input;
 pub mod system_context;
 pub mod system_contracts;
 
+#[cfg(feature = "vm2")]
+mod differential;
 #[cfg(feature = "vm2")]
 mod vm2_adapter;
 
@@ -21,7 +23,11 @@ use std::time::Instant;
 use colored::Colorize;
 
 use crate::compilers::downloader::Downloader as CompilerDownloader;
+use crate::test::case::input::output::storage_slot::StorageSlot;
+use crate::test::case::input::value::Value;
+use crate::vm::chain_context::ChainContext;
 use crate::vm::execution_result::ExecutionResult;
+use crate::vm::limits::Limits;
 
 use self::system_context::SystemContext;
 use self::system_contracts::SystemContracts;
@@ -43,6 +49,16 @@ pub struct EraVM {
     published_evm_bytecodes: HashMap<web3::types::U256, Vec<web3::types::U256>>,
     /// The storage state.
     storage: HashMap<zkevm_tester::runners::compiler_tests::StorageKey, web3::types::H256>,
+    /// Whether to run each transaction on both the reference VM and vm2, comparing the results.
+    differential: bool,
+    /// The divergences between the reference VM and vm2 found since the last check.
+    divergences: Vec<String>,
+    /// The execution limits.
+    limits: Limits,
+    /// The chain context the system context storage is initialized with.
+    chain_context: ChainContext,
+    /// The exceeded limit description since the last check.
+    limit_exceeded: Option<String>,
 }
 
 impl EraVM {
@@ -101,7 +117,8 @@ impl EraVM {
             system_contracts_save_path,
         )?;
 
-        let storage = SystemContext::create_storage();
+        let chain_context = ChainContext::default();
+        let storage = SystemContext::create_storage(&chain_context);
 
         let mut vm = Self {
             known_contracts: HashMap::new(),
@@ -110,6 +127,11 @@ impl EraVM {
             deployed_contracts: HashMap::new(),
             storage,
             published_evm_bytecodes: HashMap::new(),
+            differential: false,
+            divergences: Vec::new(),
+            limits: Limits::default(),
+            chain_context,
+            limit_exceeded: None,
         };
 
         vm.add_known_contract(
@@ -140,6 +162,58 @@ impl EraVM {
         Ok(vm)
     }
 
+    ///
+    /// Enables or disables the differential execution on the reference VM and vm2.
+    ///
+    pub fn set_differential(&mut self, value: bool) {
+        self.differential = value;
+    }
+
+    ///
+    /// Takes the divergences between the reference VM and vm2 found since the last call.
+    ///
+    pub fn take_divergence(&mut self) -> Option<String> {
+        if self.divergences.is_empty() {
+            return None;
+        }
+        Some(
+            self.divergences
+                .drain(..)
+                .collect::<Vec<String>>()
+                .join("; "),
+        )
+    }
+
+    ///
+    /// Sets the execution limits for the subsequent transactions.
+    ///
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    ///
+    /// Takes the description of the limit exceeded since the last call.
+    ///
+    pub fn take_limit_exceeded(&mut self) -> Option<String> {
+        self.limit_exceeded.take()
+    }
+
+    ///
+    /// Sets the chain context for the subsequent transactions by rewriting the system context storage.
+    ///
+    pub fn set_chain_context(&mut self, chain_context: ChainContext) {
+        if self.chain_context == chain_context {
+            return;
+        }
+        // The block hash window moves with the block number, so the previous one is removed.
+        for key in SystemContext::create_storage(&self.chain_context).into_keys() {
+            self.storage.remove(&key);
+        }
+        self.storage
+            .extend(SystemContext::create_storage(&chain_context));
+        self.chain_context = chain_context;
+    }
+
     ///
     /// Clones the VM instance from and adds known contracts for a single test run.
     ///
@@ -229,71 +303,180 @@ impl EraVM {
         );
 
         #[cfg(not(feature = "vm2"))]
-        {
-            let snapshot = zkevm_tester::runners::compiler_tests::run_vm_multi_contracts(
-                trace_file_path.to_string_lossy().to_string(),
-                self.deployed_contracts.clone(),
-                &calldata,
-                self.storage.clone(),
+        let result = self.execute_reference(
+            trace_file_path,
+            calldata.as_slice(),
+            entry_address,
+            context,
+            vm_launch_option,
+        )?;
+        #[cfg(feature = "vm2")]
+        let result = if self.differential {
+            self.execute_differential(
+                trace_file_path,
+                calldata.as_slice(),
                 entry_address,
-                Some(context),
+                context,
                 vm_launch_option,
-                usize::MAX,
-                self.known_contracts.clone(),
-                self.published_evm_bytecodes.clone(),
-                self.default_aa_code_hash,
-                self.evm_interpreter_code_hash,
-            )?;
-
-            for (address, assembly) in snapshot.deployed_contracts.iter() {
-                if self.deployed_contracts.contains_key(address) {
-                    continue;
-                }
+            )?
+        } else {
+            self.execute_vm2(
+                calldata.as_slice(),
+                entry_address,
+                context,
+                vm_launch_option,
+            )?
+        };
 
-                self.deployed_contracts
-                    .insert(*address, assembly.to_owned());
-            }
+        self.check_limits(&result);
+        Ok(result)
+    }
 
-            for (hash, preimage) in snapshot.published_sha256_blobs.iter() {
-                if self.published_evm_bytecodes.contains_key(&hash) {
-                    continue;
-                }
+    ///
+    /// Records the exceeded limit, if the transaction has hit the cycles or ergs limit.
+    ///
+    /// Only the reference VM stops at the cycles limit, so vm2 transactions are checked afterwards.
+    ///
+    fn check_limits(&mut self, result: &ExecutionResult) {
+        if let Some(cycles) = self.limits.cycles.filter(|cycles| result.cycles >= *cycles) {
+            self.limit_exceeded = Some(format!("the cycles limit of {cycles} has been exceeded"));
+        } else if let Some(ergs) = self.limits.ergs.filter(|ergs| result.ergs > *ergs) {
+            self.limit_exceeded = Some(format!(
+                "the ergs limit of {ergs} has been exceeded with {} ergs used",
+                result.ergs
+            ));
+        }
+    }
 
-                self.published_evm_bytecodes.insert(*hash, preimage.clone());
-            }
+    ///
+    /// Runs a transaction on the reference EraVM implementation.
+    ///
+    fn execute_reference(
+        &mut self,
+        trace_file_path: PathBuf,
+        calldata: &[u8],
+        entry_address: web3::types::Address,
+        context: zkevm_tester::runners::compiler_tests::VmExecutionContext,
+        vm_launch_option: zkevm_tester::runners::compiler_tests::VmLaunchOption,
+    ) -> anyhow::Result<ExecutionResult> {
+        let snapshot = zkevm_tester::runners::compiler_tests::run_vm_multi_contracts(
+            trace_file_path.to_string_lossy().to_string(),
+            self.deployed_contracts.clone(),
+            calldata,
+            self.storage.clone(),
+            entry_address,
+            Some(context),
+            vm_launch_option,
+            self.limits.cycles.unwrap_or(usize::MAX),
+            self.known_contracts.clone(),
+            self.published_evm_bytecodes.clone(),
+            self.default_aa_code_hash,
+            self.evm_interpreter_code_hash,
+        )?;
 
-            self.storage = snapshot.storage.clone();
+        for (address, assembly) in snapshot.deployed_contracts.iter() {
+            if self.deployed_contracts.contains_key(address) {
+                continue;
+            }
 
-            Ok(snapshot.into())
+            self.deployed_contracts
+                .insert(*address, assembly.to_owned());
         }
-        #[cfg(feature = "vm2")]
-        {
-            let (result, storage_changes, deployed_contracts) = vm2_adapter::run_vm(
-                self.deployed_contracts.clone(),
-                &calldata,
-                self.storage.clone(),
-                entry_address,
-                Some(context),
-                vm_launch_option,
-                self.known_contracts.clone(),
-                self.default_aa_code_hash,
-                self.evm_interpreter_code_hash,
-            )
-            .map_err(|error| anyhow::anyhow!("EraVM failure: {}", error))?;
 
-            for (key, value) in storage_changes.into_iter() {
-                self.storage.insert(key, value);
+        for (hash, preimage) in snapshot.published_sha256_blobs.iter() {
+            if self.published_evm_bytecodes.contains_key(&hash) {
+                continue;
             }
-            for (address, assembly) in deployed_contracts.into_iter() {
-                if self.deployed_contracts.contains_key(&address) {
-                    continue;
-                }
 
-                self.deployed_contracts.insert(address, assembly);
+            self.published_evm_bytecodes.insert(*hash, preimage.clone());
+        }
+
+        self.storage = snapshot.storage.clone();
+
+        Ok(snapshot.into())
+    }
+
+    ///
+    /// Runs a transaction on the vm2 EraVM implementation.
+    ///
+    #[cfg(feature = "vm2")]
+    fn execute_vm2(
+        &mut self,
+        calldata: &[u8],
+        entry_address: web3::types::Address,
+        context: zkevm_tester::runners::compiler_tests::VmExecutionContext,
+        vm_launch_option: zkevm_tester::runners::compiler_tests::VmLaunchOption,
+    ) -> anyhow::Result<ExecutionResult> {
+        let (result, storage_changes, deployed_contracts) = vm2_adapter::run_vm(
+            self.deployed_contracts.clone(),
+            calldata,
+            self.storage.clone(),
+            entry_address,
+            Some(context),
+            vm_launch_option,
+            self.known_contracts.clone(),
+            self.default_aa_code_hash,
+            self.evm_interpreter_code_hash,
+        )
+        .map_err(|error| anyhow::anyhow!("EraVM failure: {}", error))?;
+
+        for (key, value) in storage_changes.into_iter() {
+            self.storage.insert(key, value);
+        }
+        for (address, assembly) in deployed_contracts.into_iter() {
+            if self.deployed_contracts.contains_key(&address) {
+                continue;
             }
 
-            Ok(result)
+            self.deployed_contracts.insert(address, assembly);
         }
+
+        Ok(result)
+    }
+
+    ///
+    /// Runs a transaction on both the reference EraVM and vm2 from the same snapshot.
+    ///
+    /// The reference VM result and state are kept, and the divergences are recorded
+    /// to be reported by the caller.
+    ///
+    #[cfg(feature = "vm2")]
+    fn execute_differential(
+        &mut self,
+        trace_file_path: PathBuf,
+        calldata: &[u8],
+        entry_address: web3::types::Address,
+        context: zkevm_tester::runners::compiler_tests::VmExecutionContext,
+        vm_launch_option: zkevm_tester::runners::compiler_tests::VmLaunchOption,
+    ) -> anyhow::Result<ExecutionResult> {
+        let mut candidate = self.clone();
+
+        let reference_result = self.execute_reference(
+            trace_file_path,
+            calldata,
+            entry_address,
+            context.clone(),
+            vm_launch_option.clone(),
+        )?;
+        let candidate_result =
+            match candidate.execute_vm2(calldata, entry_address, context, vm_launch_option) {
+                Ok(result) => result,
+                Err(error) => {
+                    self.divergences.push(format!("vm2 error: {error}"));
+                    return Ok(reference_result);
+                }
+            };
+
+        if let Some(divergence) = differential::compare(
+            &reference_result,
+            &self.storage,
+            &candidate_result,
+            &candidate.storage,
+        ) {
+            self.divergences.push(divergence);
+        }
+
+        Ok(reference_result)
     }
 
     ///
@@ -442,6 +625,26 @@ impl EraVM {
             .expect("Contract not found");
     }
 
+    ///
+    /// Reads the actual values of the expected storage slots, which are zero if not set.
+    ///
+    pub fn read_storage_slots(&self, expected: &[StorageSlot]) -> Vec<StorageSlot> {
+        expected
+            .iter()
+            .map(|slot| {
+                let value = self
+                    .storage
+                    .get(&zkevm_tester::runners::compiler_tests::StorageKey {
+                        address: slot.address,
+                        key: slot.key,
+                    })
+                    .map(|value| web3::types::U256::from_big_endian(value.as_bytes()))
+                    .unwrap_or_default();
+                StorageSlot::new(slot.address, slot.key, Value::Certain(value))
+            })
+            .collect()
+    }
+
     ///
     /// Adds values to storage.
     ///
@@ -3,9 +3,10 @@
 //!
 
 use std::collections::HashMap;
-use std::ops::Add;
 use std::str::FromStr;
 
+use crate::vm::chain_context::ChainContext;
+
 ///
 /// The EraVM system context.
 ///
@@ -42,9 +43,6 @@ impl SystemContext {
     /// The system context virtual blocks upgrade info position in the storage.
     const SYSTEM_CONTEXT_VIRTUAL_BLOCK_UPGRADE_INFO_POSITION: u64 = 269;
 
-    /// The ZKsync chain ID.
-    const CHAIND_ID: u64 = 280;
-
     /// The default origin for tests.
     const TX_ORIGIN: &'static str =
         "0x0000000000000000000000009292929292929292929292929292929292929292";
@@ -55,39 +53,32 @@ impl SystemContext {
     /// The default block gas limit for tests.
     const BLOCK_GAS_LIMIT: u64 = (1 << 30);
 
-    /// The default coinbase for tests.
-    const COIN_BASE: &'static str =
-        "0x0000000000000000000000000000000000000000000000000000000000008001";
-
     /// The default block difficulty for tests.
     const BLOCK_DIFFICULTY: u64 = 2500000000000000;
 
-    /// The default base fee for tests.
-    const BASE_FEE: u64 = 7;
-
-    /// The default current block number for tests.
-    const CURRENT_BLOCK_NUMBER: u128 = 300;
-
-    /// The default current block timestamp for tests.
-    const CURRENT_BLOCK_TIMESTAMP: u128 = 0xdeadbeef;
-
-    /// The default zero block hash for tests.
-    const ZERO_BLOCK_HASH: &'static str =
-        "0x3737373737373737373737373737373737373737373737373737373737373737";
+    /// The number of the most recent blocks whose hashes are available.
+    const BLOCK_HASHES_AVAILABLE: u64 = 256;
 
     ///
     /// Returns the storage values for the system context.
     ///
     pub fn create_storage(
+        chain_context: &ChainContext,
     ) -> HashMap<zkevm_tester::runners::compiler_tests::StorageKey, web3::types::H256> {
+        let origin = match chain_context.origin {
+            Some(origin) => web3::types::H256::from(origin),
+            None => web3::types::H256::from_str(Self::TX_ORIGIN).expect("Always valid"),
+        };
+        let block_number = chain_context.block_number();
+
         let mut system_context_values = vec![
             (
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_CHAIN_ID_POSITION),
-                web3::types::H256::from_low_u64_be(Self::CHAIND_ID),
+                web3::types::H256::from_low_u64_be(chain_context.chain_id()),
             ),
             (
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_ORIGIN_POSITION),
-                web3::types::H256::from_str(Self::TX_ORIGIN).expect("Always valid"),
+                origin,
             ),
             (
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_GAS_PRICE_POSITION),
@@ -99,7 +90,7 @@ impl SystemContext {
             ),
             (
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_COINBASE_POSITION),
-                web3::types::H256::from_str(Self::COIN_BASE).expect("Always valid"),
+                web3::types::H256::from(chain_context.coinbase()),
             ),
             (
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_DIFFICULTY_POSITION),
@@ -107,19 +98,19 @@ impl SystemContext {
             ),
             (
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_BASE_FEE_POSITION),
-                web3::types::H256::from_low_u64_be(Self::BASE_FEE),
+                web3::types::H256::from_low_u64_be(chain_context.base_fee()),
             ),
             (
                 web3::types::H256::from_low_u64_be(
                     Self::SYSTEM_CONTEXT_VIRTUAL_BLOCK_UPGRADE_INFO_POSITION,
                 ),
-                web3::types::H256::from_low_u64_be(Self::CURRENT_BLOCK_NUMBER as u64),
+                web3::types::H256::from_low_u64_be(block_number),
             ),
         ];
 
         let block_info_bytes = [
-            Self::CURRENT_BLOCK_NUMBER.to_be_bytes(),
-            Self::CURRENT_BLOCK_TIMESTAMP.to_be_bytes(),
+            u128::from(block_number).to_be_bytes(),
+            u128::from(chain_context.block_timestamp()).to_be_bytes(),
         ]
         .concat();
 
@@ -128,17 +119,15 @@ impl SystemContext {
             web3::types::H256::from_slice(block_info_bytes.as_slice()),
         ));
 
-        for index in 0..Self::CURRENT_BLOCK_NUMBER {
-            let padded_index = [[0u8; 16], index.to_be_bytes()].concat();
+        for index in block_number.saturating_sub(Self::BLOCK_HASHES_AVAILABLE)..block_number {
+            let padded_index = [[0u8; 24], index.to_be_bytes()].concat();
             let padded_slot =
                 web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_BLOCK_HASH_POSITION)
                     .to_fixed_bytes()
                     .to_vec();
             let key = web3::signing::keccak256([padded_index, padded_slot].concat().as_slice());
 
-            let mut hash = web3::types::U256::from_str(Self::ZERO_BLOCK_HASH)
-                .expect("Invalid zero block hash const");
-            hash = hash.add(web3::types::U256::from(index));
+            let hash = ChainContext::block_hash(index);
             let mut hash_bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
             hash.to_big_endian(&mut hash_bytes);
 
@@ -10,6 +10,8 @@ use web3::ethabi::Address;
 
 use vm2::ExecutionEnd;
 use vm2::World;
+use zkevm_assembly::zkevm_opcode_defs::decoding::encoding_mode_testing::EncodingModeTesting;
+use zkevm_assembly::zkevm_opcode_defs::decoding::EncodingModeProduction;
 use zkevm_assembly::zkevm_opcode_defs::Assembly;
 use zkevm_opcode_defs::ethereum_types::{BigEndianHash, H256, U256};
 use zkevm_tester::runners::compiler_tests::FullABIParams;
@@ -18,10 +20,10 @@ use zkevm_tester::runners::compiler_tests::VmExecutionContext;
 use zkevm_tester::runners::compiler_tests::VmLaunchOption;
 
 use crate::test::case::input::{
-    output::{event::Event, Output},
+    output::{event::Event, exception_kind::ExceptionKind, Output},
     value::Value,
 };
-use crate::vm::eravm::execution_result::ExecutionResult;
+use crate::vm::execution_result::ExecutionResult;
 
 pub fn run_vm(
     contracts: HashMap<web3::ethabi::Address, Assembly>,
@@ -58,9 +60,8 @@ pub fn run_vm(
     };
 
     for (_, contract) in contracts {
-        let bytecode = contract.clone().compile_to_bytecode()?;
-        let hash = zkevm_assembly::zkevm_opcode_defs::bytecode_to_code_hash(&bytecode)?;
-        known_contracts.insert(U256::from_big_endian(&hash), contract);
+        let hash = code_hash(&contract)?;
+        known_contracts.insert(hash, contract);
     }
 
     let context = context.unwrap_or_default();
@@ -90,21 +91,39 @@ pub fn run_vm(
     vm.state.registers[4] = abi_params.r4_value.unwrap_or_default();
     vm.state.registers[5] = abi_params.r5_value.unwrap_or_default();
 
-    let output = match vm.run() {
+    let ergs_before = vm.state.current_frame.gas;
+    let cycles_before = vm.state.cycle_counter;
+
+    let execution_end = vm.run();
+
+    let ergs = ergs_before.saturating_sub(vm.state.current_frame.gas) as u64;
+    let cycles = vm.state.cycle_counter.saturating_sub(cycles_before) as usize;
+
+    let output = match execution_end {
         ExecutionEnd::ProgramFinished(return_value) => Output {
             return_data: chunk_return_data(&return_value),
             exception: false,
+            exception_kind: None,
             events: merge_events(vm.world.events()),
+            storage: vec![],
+            custom_errors: vec![],
         },
         ExecutionEnd::Reverted(return_value) => Output {
             return_data: chunk_return_data(&return_value),
             exception: true,
-            events: vec![],
+            exception_kind: Some(ExceptionKind::Revert),
+            events: merge_events(vm.world.events()),
+            storage: vec![],
+            custom_errors: vec![],
         },
+        // Every panic burns the remaining ergs, so running out of ergs cannot be told apart.
         _panic => Output {
             return_data: vec![],
             exception: true,
+            exception_kind: Some(ExceptionKind::Panic),
             events: vec![],
+            storage: vec![],
+            custom_errors: vec![],
         },
     };
 
@@ -133,17 +152,43 @@ pub fn run_vm(
         .collect::<HashMap<_, _>>();
 
     Ok((
-        ExecutionResult {
-            output,
-            cycles: 0,
-            ergs: 0,
-            gas: 0,
-        },
+        ExecutionResult::new(output, cycles, ergs, 0),
         storage_changes,
         deployed_contracts,
     ))
 }
 
+///
+/// Computes the code hash in the globally set encoding mode.
+///
+/// The hashes must match the ones written to the storage by the reference VM, which uses the
+/// testing encoding in the differential mode. The bytecode itself is always decoded by vm2 in
+/// the production encoding, as it is the only one vm2 supports.
+///
+fn code_hash(contract: &Assembly) -> anyhow::Result<U256> {
+    let hash = match zkevm_assembly::get_encoding_mode() {
+        zkevm_assembly::RunningVmEncodingMode::Testing => {
+            let bytecode = contract
+                .clone()
+                .compile_to_bytecode_for_mode::<16, EncodingModeTesting>()?;
+            zkevm_assembly::zkevm_opcode_defs::bytecode_to_code_hash_for_mode::<
+                16,
+                EncodingModeTesting,
+            >(&bytecode)?
+        }
+        zkevm_assembly::RunningVmEncodingMode::Production => {
+            let bytecode = contract
+                .clone()
+                .compile_to_bytecode_for_mode::<8, EncodingModeProduction>()?;
+            zkevm_assembly::zkevm_opcode_defs::bytecode_to_code_hash_for_mode::<
+                8,
+                EncodingModeProduction,
+            >(&bytecode)?
+        }
+    };
+    Ok(U256::from_big_endian(&hash))
+}
+
 struct TestWorld {
     storage: HashMap<StorageKey, H256>,
     contracts: HashMap<U256, Assembly>,
@@ -159,7 +204,7 @@ impl World for TestWorld {
             .get(&hash)
             .unwrap()
             .clone()
-            .compile_to_bytecode()
+            .compile_to_bytecode_for_mode::<8, EncodingModeProduction>()
             .unwrap();
         let instructions = bytecode
             .iter()
@@ -4,18 +4,82 @@
 
 use crate::vm::evm::runtime::Runtime as EVMRuntime;
 
+///
+/// The EVM precompile set type.
+///
+/// Includes `ecrecover`, `sha256`, `ripemd160`, `identity`, `modexp`, and the `bn256` curve operations.
+///
+pub type PrecompileSet<'evm> = evm_precompile::StandardPrecompileSet<'evm>;
+
+///
+/// The EVM opcode table type.
+///
+pub type Etable<'evm> =
+    evm::Etable<evm::standard::State<'evm>, EVMRuntime, evm::trap::CallCreateTrap>;
+
 ///
 /// The EVM resolver type.
 ///
-pub type Resolver<'evm> = evm::standard::EtableResolver<
-    'evm,
-    'evm,
-    'evm,
-    (),
-    evm::Etable<evm::standard::State<'evm>, EVMRuntime, evm::trap::CallCreateTrap>,
->;
+pub type Resolver<'evm> =
+    evm::standard::EtableResolver<'evm, 'evm, 'evm, PrecompileSet<'evm>, Etable<'evm>>;
 
 ///
 /// The EVM wrapped invoker type.
 ///
 pub type Invoker<'evm> = evm::standard::Invoker<'evm, 'evm, Resolver<'evm>>;
+
+///
+/// Creates the runtime opcode table, where `ORIGIN` respects the chain context origin override,
+/// and `REVERT` saves the revert data to the runtime.
+///
+/// The standard invoker always uses the transaction caller as the origin, and drops the return
+/// data of failed transactions.
+///
+pub fn etable<'evm>() -> Etable<'evm> {
+    let mut etable = Etable::runtime();
+    etable[evm::Opcode::ORIGIN.as_usize()] = eval_origin;
+    etable[evm::Opcode::REVERT.as_usize()] = eval_revert;
+    etable
+}
+
+///
+/// Pushes the chain context origin if set, and the transaction origin otherwise.
+///
+fn eval_origin<'evm>(
+    machine: &mut evm::Machine<evm::standard::State<'evm>>,
+    runtime: &mut EVMRuntime,
+    _opcode: evm::Opcode,
+    _position: usize,
+) -> evm::Control<evm::trap::CallCreateTrap> {
+    let origin = match runtime.chain_context.origin {
+        Some(origin) => origin,
+        None => {
+            AsRef::<evm::RuntimeState>::as_ref(&machine.state)
+                .transaction_context
+                .origin
+        }
+    };
+    match machine.stack.push(web3::types::H256::from(origin)) {
+        Ok(()) => evm::Control::Continue,
+        Err(error) => evm::Control::Exit(Err(error)),
+    }
+}
+
+///
+/// Executes the standard `REVERT` and saves the revert data to the runtime.
+///
+/// The data is overwritten by each nested revert, so if the transaction is reverted, the runtime
+/// holds the data of the outermost call, as its `REVERT` is executed last.
+///
+fn eval_revert<'evm>(
+    machine: &mut evm::Machine<evm::standard::State<'evm>>,
+    runtime: &mut EVMRuntime,
+    opcode: evm::Opcode,
+    position: usize,
+) -> evm::Control<evm::trap::CallCreateTrap> {
+    let control = evm::eval::eval_revert(machine, runtime, opcode, position);
+    if let evm::Control::Exit(Err(evm::ExitError::Reverted)) = control {
+        runtime.revert_data = machine.retval.clone();
+    }
+    control
+}
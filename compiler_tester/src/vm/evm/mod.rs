@@ -16,6 +16,10 @@ use std::time::Instant;
 use colored::Colorize;
 
 use crate::compilers::downloader::Downloader as CompilerDownloader;
+use crate::test::case::input::output::exception_kind::ExceptionKind;
+use crate::test::case::input::output::storage_slot::StorageSlot;
+use crate::test::case::input::value::Value;
+use crate::vm::chain_context::ChainContext;
 use crate::vm::execution_result::ExecutionResult;
 
 use self::input::build::Build as EVMBuild;
@@ -33,9 +37,19 @@ pub struct EVM<'evm> {
     builds: HashMap<String, EVMBuild>,
     /// The EVM invoker.
     invoker: EVMInvoker<'evm>,
+    /// The transaction gas limit.
+    gas_limit: u64,
+    /// The exceeded limit description since the last check.
+    limit_exceeded: Option<String>,
 }
 
 impl<'evm> EVM<'evm> {
+    /// The default transaction gas limit.
+    pub const GAS_LIMIT: u64 = 0xffffffff;
+
+    /// The transaction gas price.
+    pub const GAS_PRICE: u64 = 0xb2d05e00;
+
     ///
     /// A shortcut constructor.
     ///
@@ -46,9 +60,32 @@ impl<'evm> EVM<'evm> {
             runtime,
             builds,
             invoker,
+            gas_limit: Self::GAS_LIMIT,
+            limit_exceeded: None,
         }
     }
 
+    ///
+    /// Sets the transaction gas limit, or resets it to the default one.
+    ///
+    pub fn set_gas_limit(&mut self, gas_limit: Option<u64>) {
+        self.gas_limit = gas_limit.unwrap_or(Self::GAS_LIMIT);
+    }
+
+    ///
+    /// Takes the description of the limit exceeded since the last call.
+    ///
+    pub fn take_limit_exceeded(&mut self) -> Option<String> {
+        self.limit_exceeded.take()
+    }
+
+    ///
+    /// Sets the chain context for the subsequent transactions.
+    ///
+    pub fn set_chain_context(&mut self, chain_context: ChainContext) {
+        self.runtime.chain_context = chain_context;
+    }
+
     ///
     /// Downloads the necessary compiler binaries.
     ///
@@ -89,50 +126,45 @@ impl<'evm> EVM<'evm> {
         let mut deploy_code = build.deploy_build.bytecode.to_owned();
         deploy_code.extend(constructor_args);
 
-        self.runtime
-            .balances
-            .insert(caller, web3::types::U256::max_value());
+        self.mint_ether(
+            caller,
+            self.maximum_fee() + web3::types::U256::from(value.unwrap_or_default()),
+        );
 
-        let (address, exception) = match evm::transact(
+        let (return_data, exception, exception_kind) = match evm::transact(
             evm::standard::TransactArgs::Create {
                 caller,
                 value: value.unwrap_or_default().into(),
                 init_code: deploy_code,
                 salt: None,
-                gas_limit: web3::types::U256::from_str_radix(
-                    "ffffffff",
-                    era_compiler_common::BASE_HEXADECIMAL,
-                )
-                .expect("Always valid"),
-                gas_price: web3::types::U256::from_str_radix(
-                    "b2d05e00",
-                    era_compiler_common::BASE_HEXADECIMAL,
-                )
-                .expect("Always valid"),
+                gas_limit: self.gas_limit.into(),
+                gas_price: Self::GAS_PRICE.into(),
                 access_list: vec![],
             },
             None,
             &mut self.runtime,
             &self.invoker,
         ) {
-            Ok(evm::standard::TransactValue::Create { succeed, address }) => match succeed {
-                evm::ExitSucceed::Returned => (address, false),
-                _ => (web3::types::Address::zero(), true),
-            },
+            // `STOP` and `SELFDESTRUCT` are successful exits as well as `RETURN`.
+            Ok(evm::standard::TransactValue::Create { address, .. }) => {
+                (Self::address_word(address), false, None)
+            }
             Ok(evm::standard::TransactValue::Call { .. }) => {
                 unreachable!("The `Create` transaction must be executed above")
             }
-            Err(error) => (web3::types::Address::zero(), true),
+            Err(error) => (
+                self.failure_data(&error),
+                true,
+                Some(Self::exception_kind(&error)),
+            ),
         };
+        let gas_used = self.gas_used();
+        self.runtime.finalize_transaction();
 
-        let mut return_data = vec![
-            0u8;
-            era_compiler_common::BYTE_LENGTH_FIELD
-                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS
-        ];
-        return_data.extend(address.as_fixed_bytes());
+        self.burn_minted_ether(caller, value, exception, gas_used);
+        self.check_gas_limit(exception, gas_used);
         let events = self.runtime.logs.drain(..).collect();
-        let output = EVMOutput::new(return_data, exception, events);
+        let output = EVMOutput::new(return_data, exception, exception_kind, events, gas_used);
 
         let execution_result = ExecutionResult::from(output);
         Ok(execution_result)
@@ -149,48 +181,67 @@ impl<'evm> EVM<'evm> {
         value: Option<u128>,
         calldata: Vec<u8>,
     ) -> anyhow::Result<ExecutionResult> {
-        self.runtime
-            .balances
-            .insert(caller, web3::types::U256::max_value()); // TODO
+        self.mint_ether(
+            caller,
+            self.maximum_fee() + web3::types::U256::from(value.unwrap_or_default()),
+        );
 
-        let (return_data, exception) = match evm::transact(
+        let (return_data, exception, exception_kind) = match evm::transact(
             evm::standard::TransactArgs::Call {
                 caller,
                 address,
                 value: value.unwrap_or_default().into(),
                 data: calldata,
-                gas_limit: web3::types::U256::from_str_radix(
-                    "ffffffff",
-                    era_compiler_common::BASE_HEXADECIMAL,
-                )
-                .expect("Always valid"),
-                gas_price: web3::types::U256::from_str_radix(
-                    "b2d05e00",
-                    era_compiler_common::BASE_HEXADECIMAL,
-                )
-                .expect("Always valid"),
+                gas_limit: self.gas_limit.into(),
+                gas_price: Self::GAS_PRICE.into(),
                 access_list: vec![],
             },
             None,
             &mut self.runtime,
             &self.invoker,
         ) {
-            Ok(evm::standard::TransactValue::Call { succeed, retval }) => {
-                (retval, succeed != evm::ExitSucceed::Returned)
-            }
+            // `STOP` and `SELFDESTRUCT` are successful exits as well as `RETURN`.
+            Ok(evm::standard::TransactValue::Call { retval, .. }) => (retval, false, None),
             Ok(evm::standard::TransactValue::Create { .. }) => {
                 unreachable!("The `Call` transaction must be executed above")
             }
-            Err(error) => (vec![], true),
+            Err(error) => (
+                self.failure_data(&error),
+                true,
+                Some(Self::exception_kind(&error)),
+            ),
         };
+        let gas_used = self.gas_used();
+        self.runtime.finalize_transaction();
 
+        self.burn_minted_ether(caller, value, exception, gas_used);
+        self.check_gas_limit(exception, gas_used);
         let events = self.runtime.logs.drain(..).collect();
-        let output = EVMOutput::new(return_data, exception, events);
+        let output = EVMOutput::new(return_data, exception, exception_kind, events, gas_used);
 
         let execution_result = ExecutionResult::from(output);
         Ok(execution_result)
     }
 
+    ///
+    /// Reads the actual values of the expected storage slots, which are zero if not set.
+    ///
+    pub fn read_storage_slots(&self, expected: &[StorageSlot]) -> Vec<StorageSlot> {
+        expected
+            .iter()
+            .map(|slot| {
+                let value = self
+                    .runtime
+                    .storages
+                    .get(&slot.address)
+                    .and_then(|storage| storage.get(&crate::utils::u256_to_h256(&slot.key)))
+                    .map(|value| web3::types::U256::from_big_endian(value.as_bytes()))
+                    .unwrap_or_default();
+                StorageSlot::new(slot.address, slot.key, Value::Certain(value))
+            })
+            .collect()
+    }
+
     ///
     /// Adds values to storage.
     ///
@@ -206,4 +257,172 @@ impl<'evm> EVM<'evm> {
                 .insert(crate::utils::u256_to_h256(&key), value);
         }
     }
+
+    ///
+    /// Mints some Ether value at the specified address.
+    /// Is needed to cover the transaction value and fees.
+    ///
+    pub fn mint_ether(&mut self, address: web3::types::Address, amount: web3::types::U256) {
+        evm::RuntimeBackend::deposit(&mut self.runtime, address, amount);
+    }
+
+    ///
+    /// Burns some Ether value at the specified address.
+    ///
+    pub fn burn_ether(&mut self, address: web3::types::Address, amount: web3::types::U256) {
+        let balance = self.get_balance(address);
+        self.runtime
+            .balances
+            .insert(address, balance.saturating_sub(amount));
+    }
+
+    ///
+    /// Burns the Ether minted to the caller before the transaction, except for the paid fee.
+    ///
+    /// If the transaction has failed, its value transfer is rolled back, so the value is burned
+    /// as well, and the caller only pays for the used gas.
+    ///
+    fn burn_minted_ether(
+        &mut self,
+        caller: web3::types::Address,
+        value: Option<u128>,
+        exception: bool,
+        gas_used: u64,
+    ) {
+        let mut amount = self.maximum_fee().saturating_sub(
+            web3::types::U256::from(gas_used) * web3::types::U256::from(Self::GAS_PRICE),
+        );
+        if exception {
+            amount += web3::types::U256::from(value.unwrap_or_default());
+        }
+        self.burn_ether(caller, amount);
+    }
+
+    ///
+    /// Returns the maximum fee the transaction can be charged.
+    ///
+    /// The unspent part is burned after the transaction, so the caller only pays for the used gas.
+    ///
+    fn maximum_fee(&self) -> web3::types::U256 {
+        web3::types::U256::from(self.gas_limit) * web3::types::U256::from(Self::GAS_PRICE)
+    }
+
+    ///
+    /// Records the exceeded limit, if the transaction has failed by exhausting all its gas.
+    ///
+    fn check_gas_limit(&mut self, exception: bool, gas_used: u64) {
+        if exception && gas_used >= self.gas_limit {
+            self.limit_exceeded = Some(format!(
+                "the gas limit of {} has been exhausted",
+                self.gas_limit
+            ));
+        }
+    }
+
+    ///
+    /// Returns the contract address padded to a word.
+    ///
+    fn address_word(address: web3::types::Address) -> Vec<u8> {
+        let mut word = vec![
+            0u8;
+            era_compiler_common::BYTE_LENGTH_FIELD
+                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS
+        ];
+        word.extend(address.as_fixed_bytes());
+        word
+    }
+
+    ///
+    /// Returns the return data of the failed transaction.
+    ///
+    /// Only reverts return data, which is saved by the `REVERT` handler, since the standard invoker
+    /// drops it. Other exceptions consume the return data.
+    ///
+    fn failure_data(&mut self, error: &evm::ExitError) -> Vec<u8> {
+        match error {
+            evm::ExitError::Reverted => std::mem::take(&mut self.runtime.revert_data),
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Classifies the transaction error.
+    ///
+    fn exception_kind(error: &evm::ExitError) -> ExceptionKind {
+        match error {
+            evm::ExitError::Reverted => ExceptionKind::Revert,
+            evm::ExitError::Exception(evm::ExitException::OutOfGas) => ExceptionKind::OutOfGas,
+            evm::ExitError::Exception(
+                evm::ExitException::CallTooDeep | evm::ExitException::StackOverflow,
+            ) => ExceptionKind::DepthLimit,
+            _ => ExceptionKind::Panic,
+        }
+    }
+
+    ///
+    /// Returns the gas used by the last transaction, including the refunds.
+    ///
+    /// It is calculated from the fee settled by the invoker, rather than from the caller balance,
+    /// which may also change due to the value sent to or by the caller.
+    ///
+    fn gas_used(&mut self) -> u64 {
+        (self.runtime.take_gas_fee() / web3::types::U256::from(Self::GAS_PRICE)).low_u64()
+    }
+
+    ///
+    /// Returns the balance of the specified address.
+    ///
+    pub fn get_balance(&self, address: web3::types::Address) -> web3::types::U256 {
+        evm::RuntimeBaseBackend::balance(&self.runtime, address)
+    }
+
+    ///
+    /// Whether the storage of all contracts is empty.
+    ///
+    pub fn is_storage_empty(&self) -> bool {
+        self.runtime
+            .storages
+            .values()
+            .flat_map(|storage| storage.values())
+            .all(|value| value.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::invoker::PrecompileSet as EVMPrecompileSet;
+    use super::EVMInvoker;
+    use super::EVM;
+
+    #[test]
+    fn failed_call_with_value_keeps_balance() {
+        let config = evm::standard::Config::shanghai();
+        let etable = super::invoker::etable();
+        let precompiles = EVMPrecompileSet::new(&config);
+        let resolver = evm::standard::EtableResolver::new(&config, &precompiles, &etable);
+        let invoker = EVMInvoker::new(&config, &resolver);
+        let mut vm = EVM::new(HashMap::new(), invoker);
+
+        let caller = web3::types::Address::from_low_u64_be(0x10000);
+        let address = web3::types::Address::from_low_u64_be(0x20000);
+        // PUSH1 0x00 PUSH1 0x00 REVERT
+        vm.runtime
+            .codes
+            .insert(address, vec![0x60, 0x00, 0x60, 0x00, 0xfd]);
+        let initial_balance = web3::types::U256::from(10u128.pow(18));
+        vm.mint_ether(caller, initial_balance);
+
+        let result = vm
+            .execute_runtime_code("test".to_owned(), address, caller, Some(1000), vec![])
+            .expect("Always valid");
+        assert!(result.output.exception);
+        assert_eq!(
+            vm.get_balance(caller),
+            initial_balance
+                - web3::types::U256::from(result.gas) * web3::types::U256::from(EVM::GAS_PRICE)
+        );
+        assert_eq!(vm.get_balance(address), web3::types::U256::zero());
+    }
 }
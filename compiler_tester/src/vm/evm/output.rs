@@ -2,6 +2,8 @@
 //! The EVM output.
 //!
 
+use crate::test::case::input::output::exception_kind::ExceptionKind;
+
 ///
 /// The EVM output.
 ///
@@ -10,19 +12,31 @@ pub struct Output {
     pub return_data: Vec<u8>,
     /// The exception flag.
     pub exception: bool,
+    /// The exception kind.
+    pub exception_kind: Option<ExceptionKind>,
     /// The emitted logs.
     pub logs: Vec<evm::Log>,
+    /// The amount of gas used by the transaction.
+    pub gas_used: u64,
 }
 
 impl Output {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(return_data: Vec<u8>, exception: bool, logs: Vec<evm::Log>) -> Self {
+    pub fn new(
+        return_data: Vec<u8>,
+        exception: bool,
+        exception_kind: Option<ExceptionKind>,
+        logs: Vec<evm::Log>,
+        gas_used: u64,
+    ) -> Self {
         Self {
             return_data,
             exception,
+            exception_kind,
             logs,
+            gas_used,
         }
     }
 }
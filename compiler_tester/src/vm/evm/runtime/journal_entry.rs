@@ -0,0 +1,88 @@
+//!
+//! The EVM runtime state journal entry.
+//!
+
+use std::collections::HashMap;
+
+use super::Runtime;
+
+///
+/// The EVM runtime state journal entry.
+///
+/// Records the previous value of a single state entry modified inside a substate, so that only
+/// the touched entries are restored if the substate is reverted.
+///
+#[derive(Debug)]
+pub enum JournalEntry {
+    /// The previous contract code.
+    Code(web3::types::Address, Option<Vec<u8>>),
+    /// The previous contract balance.
+    Balance(web3::types::Address, Option<web3::types::U256>),
+    /// The previous contract nonce.
+    Nonce(web3::types::Address, Option<web3::types::U256>),
+    /// The previous value of a contract storage slot.
+    StorageSlot(
+        web3::types::Address,
+        web3::types::H256,
+        Option<web3::types::H256>,
+    ),
+    /// The previous contract storage as a whole.
+    Storage(
+        web3::types::Address,
+        Option<HashMap<web3::types::H256, web3::types::H256>>,
+    ),
+    /// The account newly marked as destroyed.
+    Deleted(web3::types::Address),
+    /// The address or storage slot newly marked as accessed.
+    Accessed((web3::types::Address, Option<web3::types::H256>)),
+}
+
+impl JournalEntry {
+    ///
+    /// Restores the previous value of the entry in the runtime.
+    ///
+    pub fn revert(self, runtime: &mut Runtime) {
+        match self {
+            Self::Code(address, code) => {
+                Self::restore(&mut runtime.codes, address, code);
+            }
+            Self::Balance(address, balance) => {
+                Self::restore(&mut runtime.balances, address, balance);
+            }
+            Self::Nonce(address, nonce) => {
+                Self::restore(&mut runtime.nonces, address, nonce);
+            }
+            Self::StorageSlot(address, index, value) => {
+                if let Some(storage) = runtime.storages.get_mut(&address) {
+                    Self::restore(storage, index, value);
+                }
+            }
+            Self::Storage(address, storage) => {
+                Self::restore(&mut runtime.storages, address, storage);
+            }
+            Self::Deleted(address) => {
+                runtime.deleted.remove(&address);
+            }
+            Self::Accessed(key) => {
+                runtime.accessed.remove(&key);
+            }
+        }
+    }
+
+    ///
+    /// Restores the previous value of the key in the map, removing the key if it was not set.
+    ///
+    fn restore<K, V>(map: &mut HashMap<K, V>, key: K, value: Option<V>)
+    where
+        K: std::hash::Hash + Eq,
+    {
+        match value {
+            Some(value) => {
+                map.insert(key, value);
+            }
+            None => {
+                map.remove(&key);
+            }
+        }
+    }
+}
@@ -0,0 +1,399 @@
+//!
+//! The EVM runtime.
+//!
+
+pub mod journal_entry;
+pub mod snapshot;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use sha3::Digest;
+
+use crate::vm::chain_context::ChainContext;
+
+use self::journal_entry::JournalEntry;
+use self::snapshot::Snapshot;
+
+///
+/// The EVM runtime.
+///
+#[derive(Debug, Default)]
+pub struct Runtime {
+    /// The contract codes.
+    pub codes: HashMap<web3::types::Address, Vec<u8>>,
+    /// The contract balances.
+    pub balances: HashMap<web3::types::Address, web3::types::U256>,
+    /// The contract nonces.
+    pub nonces: HashMap<web3::types::Address, web3::types::U256>,
+    /// The contract storages.
+    pub storages: HashMap<web3::types::Address, HashMap<web3::types::H256, web3::types::H256>>,
+    /// The contract logs.
+    pub logs: Vec<evm::Log>,
+    /// The chain context.
+    pub chain_context: ChainContext,
+    /// The accounts destroyed in the current transaction.
+    pub deleted: HashSet<web3::types::Address>,
+    /// The addresses and storage slots accessed in the current transaction.
+    pub accessed: HashSet<(web3::types::Address, Option<web3::types::H256>)>,
+    /// The data of the last `REVERT` executed in the current transaction.
+    pub revert_data: Vec<u8>,
+    /// The storage values at the beginning of the current transaction.
+    original_storages: HashMap<(web3::types::Address, web3::types::H256), web3::types::H256>,
+    /// The state snapshots taken when entering substates.
+    substates: Vec<Snapshot>,
+    /// The previous values of the state entries modified inside substates.
+    journal: Vec<JournalEntry>,
+    /// The caller and the gas fee charged by the invoker in the current transaction.
+    fee_charged: Option<(web3::types::Address, web3::types::U256)>,
+    /// The unused gas fee refunded by the invoker to the caller in the current transaction.
+    fee_refunded: Option<web3::types::U256>,
+}
+
+impl Runtime {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        codes: HashMap<web3::types::Address, Vec<u8>>,
+        balances: HashMap<web3::types::Address, web3::types::U256>,
+        nonces: HashMap<web3::types::Address, web3::types::U256>,
+        storages: HashMap<web3::types::Address, HashMap<web3::types::H256, web3::types::H256>>,
+        logs: Vec<evm::Log>,
+    ) -> Self {
+        Self {
+            codes,
+            balances,
+            nonces,
+            storages,
+            logs,
+            chain_context: ChainContext::default(),
+            deleted: HashSet::new(),
+            accessed: HashSet::new(),
+            revert_data: Vec::new(),
+            original_storages: HashMap::new(),
+            substates: Vec::new(),
+            journal: Vec::new(),
+            fee_charged: None,
+            fee_refunded: None,
+        }
+    }
+
+    ///
+    /// Takes the gas fee paid for the last transaction, that is, the charged fee minus the refund.
+    ///
+    /// The invoker charges the fee for the whole gas limit before the execution and refunds the
+    /// unused gas after it, both by direct withdrawals and deposits. The value transfers go through
+    /// `transfer` instead, so they do not affect the fee, even if the caller receives some value.
+    ///
+    pub fn take_gas_fee(&mut self) -> web3::types::U256 {
+        let charged = self
+            .fee_charged
+            .take()
+            .map(|(_, fee)| fee)
+            .unwrap_or_default();
+        let refunded = self.fee_refunded.take().unwrap_or_default();
+        charged.saturating_sub(refunded)
+    }
+
+    ///
+    /// Finalizes the transaction.
+    ///
+    /// Removes the destroyed accounts and resets the transaction-scoped state.
+    ///
+    pub fn finalize_transaction(&mut self) {
+        for address in self.deleted.drain() {
+            self.codes.remove(&address);
+            self.balances.remove(&address);
+            self.nonces.remove(&address);
+            self.storages.remove(&address);
+        }
+        self.accessed.clear();
+        self.revert_data.clear();
+        self.original_storages.clear();
+        self.substates.clear();
+        self.journal.clear();
+    }
+
+    ///
+    /// Records the previous value of a state entry, if there is a substate to revert.
+    ///
+    fn record(&mut self, entry: JournalEntry) {
+        if !self.substates.is_empty() {
+            self.journal.push(entry);
+        }
+    }
+
+    ///
+    /// Adds the value to the balance of the address.
+    ///
+    fn add_balance(&mut self, address: web3::types::H160, value: web3::types::U256) {
+        let previous = self.balances.get(&address).copied();
+        self.balances
+            .insert(address, previous.unwrap_or_default() + value);
+        self.record(JournalEntry::Balance(address, previous));
+    }
+
+    ///
+    /// Subtracts the value from the balance of the address.
+    ///
+    fn sub_balance(
+        &mut self,
+        address: web3::types::H160,
+        value: web3::types::U256,
+    ) -> Result<(), evm::ExitError> {
+        let balance = self
+            .balances
+            .get(&address)
+            .copied()
+            .ok_or(evm::ExitError::Exception(evm::ExitException::OutOfFund))?;
+        if balance < value {
+            return Err(evm::ExitError::Exception(evm::ExitException::OutOfFund));
+        }
+        self.balances.insert(address, balance - value);
+        self.record(JournalEntry::Balance(address, Some(balance)));
+        Ok(())
+    }
+}
+
+impl evm::RuntimeEnvironment for Runtime {
+    fn block_hash(&self, number: web3::types::U256) -> web3::types::H256 {
+        let block_number = web3::types::U256::from(self.chain_context.block_number());
+        // Only the hashes of the last 256 blocks are available, as on EraVM.
+        if number >= block_number || block_number - number > web3::types::U256::from(256) {
+            return web3::types::H256::zero();
+        }
+        crate::utils::u256_to_h256(&ChainContext::block_hash(number.as_u64()))
+    }
+
+    fn block_number(&self) -> web3::types::U256 {
+        web3::types::U256::from(self.chain_context.block_number())
+    }
+
+    fn block_coinbase(&self) -> web3::types::H160 {
+        self.chain_context.coinbase()
+    }
+
+    fn block_timestamp(&self) -> web3::types::U256 {
+        web3::types::U256::from(self.chain_context.block_timestamp())
+    }
+
+    fn block_difficulty(&self) -> web3::types::U256 {
+        web3::types::U256::from_str_radix("8e1bc9bf04000", era_compiler_common::BASE_HEXADECIMAL)
+            .expect("Always valid")
+    }
+
+    fn block_randomness(&self) -> Option<web3::types::H256> {
+        None
+    }
+
+    fn block_gas_limit(&self) -> web3::types::U256 {
+        web3::types::U256::from_str_radix("40000000", era_compiler_common::BASE_HEXADECIMAL)
+            .expect("Always valid")
+    }
+
+    fn block_base_fee_per_gas(&self) -> web3::types::U256 {
+        web3::types::U256::from(self.chain_context.base_fee())
+    }
+
+    fn chain_id(&self) -> web3::types::U256 {
+        web3::types::U256::from(self.chain_context.chain_id())
+    }
+}
+
+impl evm::RuntimeBaseBackend for Runtime {
+    fn balance(&self, address: web3::types::H160) -> web3::types::U256 {
+        self.balances
+            .get(&address)
+            .cloned()
+            .unwrap_or(web3::types::U256::zero())
+    }
+
+    fn code_size(&self, address: web3::types::H160) -> web3::types::U256 {
+        self.codes
+            .get(&address)
+            .map(|code| web3::types::U256::from(code.len()))
+            .unwrap_or(web3::types::U256::zero())
+    }
+
+    fn code_hash(&self, address: web3::types::H160) -> web3::types::H256 {
+        if !evm::RuntimeBaseBackend::exists(self, address) {
+            return web3::types::H256::zero();
+        }
+
+        let code = evm::RuntimeBaseBackend::code(self, address);
+        web3::types::H256::from_slice(sha3::Keccak256::digest(code.as_slice()).as_slice())
+    }
+
+    fn code(&self, address: web3::types::H160) -> Vec<u8> {
+        self.codes.get(&address).cloned().unwrap_or_default()
+    }
+
+    fn storage(&self, address: web3::types::H160, index: web3::types::H256) -> web3::types::H256 {
+        self.storages
+            .get(&address)
+            .and_then(|storage| storage.get(&index))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn exists(&self, address: web3::types::H160) -> bool {
+        self.codes.contains_key(&address)
+            || !evm::RuntimeBaseBackend::balance(self, address).is_zero()
+            || !evm::RuntimeBaseBackend::nonce(self, address).is_zero()
+    }
+
+    fn nonce(&self, address: web3::types::H160) -> web3::types::U256 {
+        self.nonces.get(&address).copied().unwrap_or_default()
+    }
+}
+
+impl evm::RuntimeBackend for Runtime {
+    fn original_storage(
+        &self,
+        address: web3::types::H160,
+        index: web3::types::H256,
+    ) -> web3::types::H256 {
+        self.original_storages
+            .get(&(address, index))
+            .copied()
+            .unwrap_or_else(|| evm::RuntimeBaseBackend::storage(self, address, index))
+    }
+
+    fn deleted(&self, address: web3::types::H160) -> bool {
+        self.deleted.contains(&address)
+    }
+
+    fn is_cold(&self, address: web3::types::H160, index: Option<web3::types::H256>) -> bool {
+        !self.accessed.contains(&(address, index))
+    }
+
+    fn is_hot(&self, address: web3::types::H160, index: Option<web3::types::H256>) -> bool {
+        !self.is_cold(address, index)
+    }
+
+    fn mark_hot(&mut self, address: web3::types::H160, index: Option<web3::types::H256>) {
+        if self.accessed.insert((address, index)) {
+            self.record(JournalEntry::Accessed((address, index)));
+        }
+    }
+
+    fn set_storage(
+        &mut self,
+        address: web3::types::H160,
+        index: web3::types::H256,
+        value: web3::types::H256,
+    ) -> Result<(), evm::ExitError> {
+        let original = evm::RuntimeBaseBackend::storage(self, address, index);
+        self.original_storages
+            .entry((address, index))
+            .or_insert(original);
+        match self.storages.get_mut(&address) {
+            Some(storage) => {
+                let previous = storage.insert(index, value);
+                self.record(JournalEntry::StorageSlot(address, index, previous));
+            }
+            None => {
+                let mut storage = HashMap::new();
+                storage.insert(index, value);
+                self.storages.insert(address, storage);
+                self.record(JournalEntry::Storage(address, None));
+            }
+        }
+        Ok(())
+    }
+
+    fn log(&mut self, log: evm::Log) -> Result<(), evm::ExitError> {
+        self.logs.push(log);
+        Ok(())
+    }
+
+    fn mark_delete(&mut self, address: web3::types::H160) {
+        if self.deleted.insert(address) {
+            self.record(JournalEntry::Deleted(address));
+        }
+    }
+
+    fn reset_storage(&mut self, address: web3::types::H160) {
+        let previous = self.storages.remove(&address);
+        self.record(JournalEntry::Storage(address, previous));
+    }
+
+    fn set_code(
+        &mut self,
+        address: web3::types::H160,
+        code: Vec<u8>,
+    ) -> Result<(), evm::ExitError> {
+        let previous = self.codes.insert(address, code);
+        self.record(JournalEntry::Code(address, previous));
+        Ok(())
+    }
+
+    fn reset_balance(&mut self, address: web3::types::H160) {
+        let previous = self.balances.insert(address, web3::types::U256::zero());
+        self.record(JournalEntry::Balance(address, previous));
+    }
+
+    fn deposit(&mut self, target: web3::types::H160, value: web3::types::U256) {
+        if matches!(self.fee_charged, Some((caller, _)) if caller == target)
+            && self.fee_refunded.is_none()
+        {
+            self.fee_refunded = Some(value);
+        }
+        self.add_balance(target, value);
+    }
+
+    fn withdrawal(
+        &mut self,
+        source: web3::types::H160,
+        value: web3::types::U256,
+    ) -> Result<(), evm::ExitError> {
+        self.sub_balance(source, value)?;
+        if self.fee_charged.is_none() {
+            self.fee_charged = Some((source, value));
+        }
+        Ok(())
+    }
+
+    fn transfer(&mut self, transfer: evm::Transfer) -> Result<(), evm::ExitError> {
+        self.sub_balance(transfer.source, transfer.value)?;
+        self.add_balance(transfer.target, transfer.value);
+        Ok(())
+    }
+
+    fn inc_nonce(&mut self, address: web3::types::H160) -> Result<(), evm::ExitError> {
+        let previous = self.nonces.get(&address).copied();
+        self.nonces.insert(
+            address,
+            previous.unwrap_or_default() + web3::types::U256::one(),
+        );
+        self.record(JournalEntry::Nonce(address, previous));
+        Ok(())
+    }
+}
+
+impl evm::TransactionalBackend for Runtime {
+    fn push_substate(&mut self) {
+        let snapshot = Snapshot::new(self.journal.len(), self.logs.len());
+        self.substates.push(snapshot);
+    }
+
+    fn pop_substate(&mut self, strategy: evm::MergeStrategy) {
+        let snapshot = match self.substates.pop() {
+            Some(snapshot) => snapshot,
+            None => return,
+        };
+        if let evm::MergeStrategy::Commit = strategy {
+            if self.substates.is_empty() {
+                self.journal.clear();
+            }
+            return;
+        }
+
+        let entries = self.journal.split_off(snapshot.journal_length);
+        for entry in entries.into_iter().rev() {
+            entry.revert(self);
+        }
+        self.logs.truncate(snapshot.logs_length);
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! The EVM runtime state snapshot.
+//!
+
+///
+/// The EVM runtime state snapshot.
+///
+/// Is taken when entering a substate. Only the journal entries recorded after it are reverted if
+/// the substate is reverted, so the state is never copied as a whole.
+///
+#[derive(Debug)]
+pub struct Snapshot {
+    /// The number of journal entries recorded before the substate.
+    pub journal_length: usize,
+    /// The number of logs emitted before the substate.
+    pub logs_length: usize,
+}
+
+impl Snapshot {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(journal_length: usize, logs_length: usize) -> Self {
+        Self {
+            journal_length,
+            logs_length,
+        }
+    }
+}
@@ -50,11 +50,13 @@ impl From<zkevm_tester::runners::compiler_tests::VmSnapshot> for ExecutionResult
 
 impl From<EVMOutput> for ExecutionResult {
     fn from(output: EVMOutput) -> Self {
+        let gas = output.gas_used;
+
         Self {
             output: Output::from(output),
             cycles: 0,
             ergs: 0,
-            gas: 0,
+            gas,
         }
     }
 }
@@ -0,0 +1,85 @@
+//!
+//! The VM execution limits.
+//!
+
+use std::time::Duration;
+
+///
+/// The VM execution limits.
+///
+/// The global defaults are set from the command line, and can be overridden by each test case.
+/// The EraVM cycles and ergs are always limited, so an infinite loop cannot hang the run.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum number of EraVM cycles per transaction.
+    pub cycles: Option<usize>,
+    /// The maximum number of EraVM ergs per transaction.
+    pub ergs: Option<u64>,
+    /// The EVM transaction gas limit.
+    pub gas: Option<u64>,
+    /// The wall-clock time limit per test.
+    pub timeout: Option<Duration>,
+}
+
+/// The one-time initialization cell for the global default limits.
+static LIMITS: once_cell::sync::OnceCell<Limits> = once_cell::sync::OnceCell::new();
+
+impl Limits {
+    /// The built-in default maximum number of EraVM cycles per transaction.
+    pub const DEFAULT_CYCLES: usize = 100_000_000;
+
+    /// The built-in default maximum number of EraVM ergs per transaction.
+    pub const DEFAULT_ERGS: u64 = 1_000_000_000;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        cycles: Option<usize>,
+        ergs: Option<u64>,
+        gas: Option<u64>,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            cycles,
+            ergs,
+            gas,
+            timeout,
+        }
+    }
+
+    ///
+    /// Initializes the global default limits.
+    ///
+    pub fn initialize(self) -> anyhow::Result<()> {
+        LIMITS
+            .set(self)
+            .map_err(|_| anyhow::anyhow!("The default limits are already initialized"))?;
+        Ok(())
+    }
+
+    ///
+    /// Returns the global default limits, with the unset EraVM limits taken from the built-in ones.
+    ///
+    pub fn get() -> Self {
+        LIMITS.get().copied().unwrap_or_default().or(Self::new(
+            Some(Self::DEFAULT_CYCLES),
+            Some(Self::DEFAULT_ERGS),
+            None,
+            None,
+        ))
+    }
+
+    ///
+    /// Returns the limits with the unset values taken from `defaults`.
+    ///
+    pub fn or(self, defaults: Self) -> Self {
+        Self {
+            cycles: self.cycles.or(defaults.cycles),
+            ergs: self.ergs.or(defaults.ergs),
+            gas: self.gas.or(defaults.gas),
+            timeout: self.timeout.or(defaults.timeout),
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //!
 
 pub mod address_iterator;
+pub mod chain_context;
 pub mod eravm;
 pub mod evm;
 pub mod execution_result;
+pub mod limits;
@@ -0,0 +1,19 @@
+//!
+//! The test process isolation role.
+//!
+
+///
+/// The test process isolation role.
+///
+#[derive(Debug, Clone)]
+pub enum Isolation {
+    /// The parent process, which distributes the tests among the worker processes.
+    Parent {
+        /// The number of worker processes.
+        workers: usize,
+        /// The additional worker arguments, which pass the artifacts prepared by the parent.
+        arguments: Vec<String>,
+    },
+    /// The worker process, which runs the tests requested by the parent process.
+    Worker,
+}
@@ -0,0 +1,330 @@
+//!
+//! The process-isolated test worker.
+//!
+
+pub mod isolation;
+pub mod request;
+pub mod response;
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::io::Read;
+use std::io::Write;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::summary::element::outcome::Outcome;
+use crate::summary::element::Element;
+use crate::summary::Summary;
+use crate::vm::limits::Limits;
+use crate::Test;
+
+use self::request::Request;
+use self::response::Response;
+
+///
+/// The process-isolated test worker.
+///
+/// The parent process sends requests to the worker standard input as JSON lines.
+/// The worker runs the requested tests one by one, and replies with prefixed JSON lines
+/// to its standard output. Other output lines are forwarded to the parent standard output.
+///
+pub struct Worker {
+    /// The worker child process.
+    child: std::process::Child,
+    /// The worker standard input.
+    stdin: std::process::ChildStdin,
+    /// The worker standard output lines.
+    stdout: mpsc::Receiver<String>,
+    /// The worker standard error collected since the last request.
+    stderr: Arc<Mutex<Vec<u8>>>,
+    /// The worker standard error reader thread.
+    stderr_reader: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    /// The worker command line flag.
+    pub const FLAG: &'static str = "--worker";
+
+    /// The worker response line prefix.
+    const RESPONSE_PREFIX: &'static str = "#compiler-tester-worker# ";
+
+    /// The maximum number of the worker standard error trailing bytes reported on crash.
+    const STDERR_TAIL_LENGTH: usize = 4096;
+
+    ///
+    /// Spawns a worker process with the same arguments as the current process.
+    ///
+    /// The `arguments` are appended to pass the artifacts prepared by the parent process,
+    /// so that the worker does not repeat the expensive startup steps.
+    ///
+    pub fn spawn(arguments: &[String]) -> anyhow::Result<Self> {
+        let executable = std::env::current_exe()?;
+        let mut child = std::process::Command::new(executable.as_path())
+            .args(std::env::args().skip(1))
+            .args(arguments)
+            .arg(Self::FLAG)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|error| anyhow::anyhow!("Worker process spawning error: {}", error))?;
+
+        let stdin = child.stdin.take().expect("Always exists");
+        let child_stdout = std::io::BufReader::new(child.stdout.take().expect("Always exists"));
+        let mut child_stderr = child.stderr.take().expect("Always exists");
+
+        let (stdout_writer, stdout) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in child_stdout.lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(_) => break,
+                };
+                if stdout_writer.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let stderr = Arc::new(Mutex::new(Vec::new()));
+        let stderr_writer = stderr.clone();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut buffer = [0u8; 4096];
+            while let Ok(size) = child_stderr.read(&mut buffer) {
+                if size == 0 {
+                    break;
+                }
+                stderr_writer
+                    .lock()
+                    .expect("Sync")
+                    .extend_from_slice(&buffer[..size]);
+            }
+        });
+
+        Ok(Self {
+            child,
+            stdin,
+            stdout,
+            stderr,
+            stderr_reader: Some(stderr_reader),
+        })
+    }
+
+    ///
+    /// Distributes the tests among `workers` worker processes and collects their results.
+    ///
+    /// If a worker crashes or exceeds the timeout, its current test is reported as crashed
+    /// or failed respectively, and the worker is replaced.
+    ///
+    pub fn dispatch(
+        summary: Arc<Mutex<Summary>>,
+        tests: Vec<Test>,
+        workers: usize,
+        arguments: &[String],
+    ) -> anyhow::Result<()> {
+        let queue = Mutex::new(tests.into_iter().collect::<VecDeque<Test>>());
+        let queue = &queue;
+        let summary = &summary;
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers.max(1))
+                .map(|_| {
+                    scope.spawn(move || -> anyhow::Result<()> {
+                        let mut worker = Self::spawn(arguments)?;
+                        loop {
+                            let (test, _compiler, mode) =
+                                match queue.lock().expect("Sync").pop_front() {
+                                    Some(test) => test,
+                                    None => break,
+                                };
+
+                            let request =
+                                Request::new(test.identifier().to_owned(), mode.to_string());
+                            match worker.run(&request) {
+                                Ok(elements) => {
+                                    for (name, has_mode, outcome) in elements.into_iter() {
+                                        let mode = if has_mode { Some(mode.clone()) } else { None };
+                                        Summary::forward(
+                                            summary.clone(),
+                                            Element::new(mode, name, outcome),
+                                        );
+                                    }
+                                }
+                                Err(outcome) => {
+                                    Summary::forward(
+                                        summary.clone(),
+                                        Element::new(Some(mode), request.identifier, outcome),
+                                    );
+                                    worker = Self::spawn(arguments)?;
+                                }
+                            }
+                        }
+                        Ok(())
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("Worker thread panicked"))
+                .collect::<anyhow::Result<()>>()
+        })
+    }
+
+    ///
+    /// Serves the parent process requests until its standard input is closed.
+    ///
+    pub fn serve<F>(summary: Arc<Mutex<Summary>>, tests: Vec<Test>, f: F) -> anyhow::Result<()>
+    where
+        F: Fn(Test),
+    {
+        let mut tests: HashMap<(String, String), Test> = tests
+            .into_iter()
+            .map(|test| ((test.0.identifier().to_owned(), test.2.to_string()), test))
+            .collect();
+
+        for line in std::io::stdin().lock().lines() {
+            let request: Request = serde_json::from_str(line?.as_str())
+                .map_err(|error| anyhow::anyhow!("Invalid worker request: {}", error))?;
+
+            match tests.remove(&(request.identifier.clone(), request.mode.clone())) {
+                Some(test) => f(test),
+                None => Summary::invalid(
+                    summary.clone(),
+                    None,
+                    request.identifier,
+                    format!(
+                        "The test is not found in the worker in mode `{}`",
+                        request.mode
+                    ),
+                ),
+            }
+
+            for element in Summary::take_elements(summary.clone()).into_iter() {
+                Self::respond(Response::Element {
+                    name: element.name,
+                    has_mode: element.mode.is_some(),
+                    outcome: element.outcome,
+                })?;
+            }
+            Self::respond(Response::Done)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Runs a single request in the worker process.
+    ///
+    /// Returns the outcome of the whole request if the worker has crashed or exceeded the timeout
+    /// before completing it.
+    ///
+    fn run(&mut self, request: &Request) -> Result<Vec<(String, bool, Outcome)>, Outcome> {
+        self.stderr.lock().expect("Sync").clear();
+
+        let timeout = Limits::get().timeout;
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        let request = serde_json::to_string(request).expect("Always valid");
+        if writeln!(self.stdin, "{request}")
+            .and_then(|_| self.stdin.flush())
+            .is_err()
+        {
+            return Err(Outcome::crashed(self.crash_reason()));
+        }
+
+        let mut elements = Vec::new();
+        loop {
+            let line = match deadline {
+                Some(deadline) => self
+                    .stdout
+                    .recv_timeout(deadline.saturating_duration_since(Instant::now())),
+                None => self
+                    .stdout
+                    .recv()
+                    .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+            };
+            let line = match line {
+                Ok(line) => line,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    let _ = self.child.kill();
+                    let timeout = timeout.unwrap_or_default();
+                    return Err(Outcome::limit_exceeded(
+                        None,
+                        format!("the timeout of {}s has been exceeded", timeout.as_secs()),
+                        vec![],
+                    ));
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(Outcome::crashed(self.crash_reason()))
+                }
+            };
+
+            let response = match line.strip_prefix(Self::RESPONSE_PREFIX) {
+                Some(response) => response,
+                None => {
+                    println!("{line}");
+                    continue;
+                }
+            };
+            match serde_json::from_str(response) {
+                Ok(Response::Element {
+                    name,
+                    has_mode,
+                    outcome,
+                }) => elements.push((name, has_mode, outcome)),
+                Ok(Response::Done) => return Ok(elements),
+                Err(error) => {
+                    return Err(Outcome::crashed(format!(
+                        "Invalid worker response: {error}"
+                    )))
+                }
+            }
+        }
+    }
+
+    ///
+    /// Waits for the crashed worker and describes its exit status and error output.
+    ///
+    fn crash_reason(&mut self) -> String {
+        let status = match self.child.wait() {
+            Ok(status) => status.to_string(),
+            Err(error) => format!("unknown status: {error}"),
+        };
+        if let Some(stderr_reader) = self.stderr_reader.take() {
+            let _ = stderr_reader.join();
+        }
+
+        let stderr = self.stderr.lock().expect("Sync");
+        let tail = &stderr[stderr.len().saturating_sub(Self::STDERR_TAIL_LENGTH)..];
+        let tail = String::from_utf8_lossy(tail);
+        let tail = tail.trim();
+        if tail.is_empty() {
+            format!("Worker crashed with {status}")
+        } else {
+            format!("Worker crashed with {status}: {tail}")
+        }
+    }
+
+    ///
+    /// Sends a response to the parent process.
+    ///
+    fn respond(response: Response) -> anyhow::Result<()> {
+        let response = serde_json::to_string(&response)?;
+        let mut stdout = std::io::stdout().lock();
+        writeln!(stdout, "{}{}", Self::RESPONSE_PREFIX, response)?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,28 @@
+//!
+//! The worker process request.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The worker process request.
+///
+/// Identifies a single (test, mode) pair to run.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Request {
+    /// The test identifier.
+    pub identifier: String,
+    /// The mode string.
+    pub mode: String,
+}
+
+impl Request {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(identifier: String, mode: String) -> Self {
+        Self { identifier, mode }
+    }
+}
@@ -0,0 +1,26 @@
+//!
+//! The worker process response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::summary::element::outcome::Outcome;
+
+///
+/// The worker process response.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    /// A summary element produced by the requested test.
+    Element {
+        /// The element name.
+        name: String,
+        /// Whether the element has a mode, which is the mode of the request.
+        has_mode: bool,
+        /// The element outcome.
+        outcome: Outcome,
+    },
+    /// The requested test has been completed.
+    Done,
+}
@@ -13,6 +13,8 @@ pub enum Workflow {
     BuildOnly,
     /// Build and execute tests.
     BuildAndRun,
+    /// Only list the scheduled tests but neither build nor execute them.
+    List,
 }
 
 impl FromStr for Workflow {
@@ -22,7 +24,8 @@ impl FromStr for Workflow {
         match day {
             "build" => Ok(Workflow::BuildOnly),
             "run" => Ok(Workflow::BuildAndRun),
-            _ => Err("Could not parse workflow. Supported workflows: build, run."),
+            "list" => Ok(Workflow::List),
+            _ => Err("Could not parse workflow. Supported workflows: build, run, list."),
         }
     }
 }
@@ -193,7 +193,9 @@ impl TryFrom<parser::Call> for FunctionCall {
                     }
                     "isoltest_side_effects_test" => {
                         if !events.is_empty() {
-                            anyhow::bail!("standard functions don't emit events");
+                            anyhow::bail!(
+                                "isoltest_side_effects_test side effects expectations are not supported"
+                            );
                         }
                         if !gas_options.is_empty() {
                             anyhow::bail!("standard functions can not have gas options");